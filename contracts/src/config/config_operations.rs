@@ -0,0 +1,238 @@
+use anchor_lang::prelude::*;
+use crate::errors::BodyDfiError;
+use crate::config::config_state::*;
+use crate::governance::governance_operations::execute_parameter_change;
+use crate::governance::governance_state::PARAMETER_KIND_UNPAUSE;
+
+/// Initialize the program-wide configuration
+pub fn initialize_program_config(
+    ctx: Context<InitializeProgramConfig>,
+    treasury: Pubkey,
+    holder_rewards_vault: Pubkey,
+    feature_flags: u64,
+) -> Result<()> {
+    let program_config = &mut ctx.accounts.program_config;
+    let admin = &ctx.accounts.admin;
+    
+    // Initialize config
+    program_config.admin = admin.key();
+    program_config.pending_admin = Pubkey::default();
+    program_config.treasury = treasury;
+    program_config.holder_rewards_vault = holder_rewards_vault;
+    program_config.feature_flags = feature_flags;
+    program_config.guardian = admin.key();
+    program_config.pause_flags = 0;
+    program_config.governance_mint = Pubkey::default();
+    program_config.bump = ctx.bumps.program_config;
+    
+    // Emit event
+    emit!(ProgramConfigUpdatedEvent {
+        admin: admin.key(),
+        treasury,
+        holder_rewards_vault,
+        feature_flags,
+    });
+    
+    Ok(())
+}
+
+/// Update the treasury addresses and feature flags
+pub fn update_program_config(
+    ctx: Context<UpdateProgramConfig>,
+    treasury: Pubkey,
+    holder_rewards_vault: Pubkey,
+    feature_flags: u64,
+) -> Result<()> {
+    let program_config = &mut ctx.accounts.program_config;
+    
+    program_config.treasury = treasury;
+    program_config.holder_rewards_vault = holder_rewards_vault;
+    program_config.feature_flags = feature_flags;
+    
+    // Emit event
+    emit!(ProgramConfigUpdatedEvent {
+        admin: program_config.admin,
+        treasury,
+        holder_rewards_vault,
+        feature_flags,
+    });
+    
+    Ok(())
+}
+
+/// Propose a new admin; the default pubkey cancels a pending proposal
+pub fn propose_admin_transfer(
+    ctx: Context<UpdateProgramConfig>,
+    new_admin: Pubkey,
+) -> Result<()> {
+    let program_config = &mut ctx.accounts.program_config;
+    program_config.pending_admin = new_admin;
+    
+    // Emit event
+    emit!(AuthorityTransferProposedEvent {
+        account: program_config.key(),
+        current_authority: program_config.admin,
+        pending_authority: new_admin,
+    });
+    
+    Ok(())
+}
+
+/// Accept a pending admin transfer
+pub fn accept_admin_transfer(ctx: Context<AcceptAdminTransfer>) -> Result<()> {
+    let program_config = &mut ctx.accounts.program_config;
+    let previous_admin = program_config.admin;
+    
+    program_config.admin = program_config.pending_admin;
+    program_config.pending_admin = Pubkey::default();
+    
+    // Emit event
+    emit!(AuthorityTransferAcceptedEvent {
+        account: program_config.key(),
+        previous_authority: previous_admin,
+        new_authority: program_config.admin,
+    });
+    
+    Ok(())
+}
+
+/// Set the guardian allowed to pause subsystems
+pub fn set_guardian(
+    ctx: Context<UpdateProgramConfig>,
+    guardian: Pubkey,
+) -> Result<()> {
+    let program_config = &mut ctx.accounts.program_config;
+    program_config.guardian = guardian;
+    
+    // Emit event
+    emit!(GuardianUpdatedEvent {
+        guardian,
+    });
+    
+    Ok(())
+}
+
+/// Pin the BodyDFi mint used for proposal and voting balances
+pub fn set_governance_mint(ctx: Context<SetGovernanceMint>) -> Result<()> {
+    let program_config = &mut ctx.accounts.program_config;
+    program_config.governance_mint = ctx.accounts.governance_token_mint.mint;
+    
+    // Emit event
+    emit!(GovernanceMintSetEvent {
+        governance_mint: program_config.governance_mint,
+    });
+    
+    Ok(())
+}
+
+/// Pause subsystems (guardian or admin)
+pub fn pause(
+    ctx: Context<Pause>,
+    subsystems: u8,
+) -> Result<()> {
+    require!(
+        subsystems != 0 && subsystems & !PAUSE_ALL == 0,
+        BodyDfiError::InvalidPauseFlags
+    );
+    
+    let program_config = &mut ctx.accounts.program_config;
+    program_config.pause_flags |= subsystems;
+    
+    // Emit event
+    emit!(PauseFlagsUpdatedEvent {
+        updated_by: ctx.accounts.signer.key(),
+        pause_flags: program_config.pause_flags,
+    });
+    
+    Ok(())
+}
+
+/// Unpause subsystems (admin only)
+pub fn unpause(
+    ctx: Context<UpdateProgramConfig>,
+    subsystems: u8,
+) -> Result<()> {
+    require!(
+        subsystems != 0 && subsystems & !PAUSE_ALL == 0,
+        BodyDfiError::InvalidPauseFlags
+    );
+    
+    let program_config = &mut ctx.accounts.program_config;
+    program_config.pause_flags &= !subsystems;
+    
+    // Emit event
+    emit!(PauseFlagsUpdatedEvent {
+        updated_by: ctx.accounts.admin.key(),
+        pause_flags: program_config.pause_flags,
+    });
+    
+    Ok(())
+}
+
+/// Unpause subsystems by executing a passed governance proposal
+pub fn execute_unpause(ctx: Context<ExecuteUnpause>) -> Result<()> {
+    let parameter_change = &ctx.accounts.parameter_change;
+    execute_parameter_change(&mut ctx.accounts.proposal, parameter_change, PARAMETER_KIND_UNPAUSE)?;
+    
+    let subsystems = u8::try_from(parameter_change.values[0])
+        .map_err(|_| BodyDfiError::InvalidPauseFlags)?;
+    require!(
+        subsystems != 0 && subsystems & !PAUSE_ALL == 0,
+        BodyDfiError::InvalidPauseFlags
+    );
+    
+    let program_config = &mut ctx.accounts.program_config;
+    program_config.pause_flags &= !subsystems;
+    
+    // Emit event
+    emit!(PauseFlagsUpdatedEvent {
+        updated_by: ctx.accounts.proposal.key(),
+        pause_flags: program_config.pause_flags,
+    });
+    
+    Ok(())
+}
+
+/// Event emitted when the program config is created or updated
+#[event]
+pub struct ProgramConfigUpdatedEvent {
+    pub admin: Pubkey,
+    pub treasury: Pubkey,
+    pub holder_rewards_vault: Pubkey,
+    pub feature_flags: u64,
+}
+
+/// Event emitted when the governance mint is set
+#[event]
+pub struct GovernanceMintSetEvent {
+    pub governance_mint: Pubkey,
+}
+
+/// Event emitted when an authority transfer is proposed
+#[event]
+pub struct AuthorityTransferProposedEvent {
+    pub account: Pubkey,
+    pub current_authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+/// Event emitted when an authority transfer is accepted
+#[event]
+pub struct AuthorityTransferAcceptedEvent {
+    pub account: Pubkey,
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+/// Event emitted when the guardian changes
+#[event]
+pub struct GuardianUpdatedEvent {
+    pub guardian: Pubkey,
+}
+
+/// Event emitted when subsystems are paused or unpaused
+#[event]
+pub struct PauseFlagsUpdatedEvent {
+    pub updated_by: Pubkey,
+    pub pause_flags: u8,
+}
\ No newline at end of file
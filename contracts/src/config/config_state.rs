@@ -0,0 +1,180 @@
+use anchor_lang::prelude::*;
+use crate::governance::governance_state::{ParameterChange, Proposal};
+
+/// Pausable subsystems
+pub const PAUSE_DATA: u8 = 1 << 0;
+pub const PAUSE_MARKETPLACE: u8 = 1 << 1;
+pub const PAUSE_REWARDS: u8 = 1 << 2;
+pub const PAUSE_GOVERNANCE: u8 = 1 << 3;
+pub const PAUSE_ALL: u8 = PAUSE_DATA | PAUSE_MARKETPLACE | PAUSE_REWARDS | PAUSE_GOVERNANCE;
+
+/// Initialize program config account context
+#[derive(Accounts)]
+pub struct InitializeProgramConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"program-config"],
+        bump,
+        space = 8 + ProgramConfig::LEN
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    /// Only the program upgrade authority may create the config
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key()) @ BodyDfiError::InvalidAuthority
+    )]
+    pub program: Program<'info, crate::program::Bodydfi>,
+    
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(admin.key()) @ BodyDfiError::InvalidAuthority
+    )]
+    pub program_data: Account<'info, ProgramData>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Update program config account context
+#[derive(Accounts)]
+pub struct UpdateProgramConfig<'info> {
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = program_config.admin == admin.key() @ BodyDfiError::InvalidAuthority
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+}
+
+/// Set governance mint account context
+#[derive(Accounts)]
+pub struct SetGovernanceMint<'info> {
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = program_config.admin == admin.key() @ BodyDfiError::InvalidAuthority,
+        constraint = program_config.governance_mint == Pubkey::default() @ BodyDfiError::GovernanceMintAlreadySet
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    /// Program record of the BodyDFi token that carries voting power
+    #[account(
+        seeds = [b"token-mint", governance_token_mint.mint.as_ref()],
+        bump,
+        constraint = !governance_token_mint.is_move_token @ BodyDfiError::InvalidMint
+    )]
+    pub governance_token_mint: Account<'info, TokenMint>,
+}
+
+/// Accept admin transfer account context
+#[derive(Accounts)]
+pub struct AcceptAdminTransfer<'info> {
+    pub pending_admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = program_config.pending_admin == pending_admin.key() @ BodyDfiError::InvalidAuthority
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+}
+
+/// Pause account context
+#[derive(Accounts)]
+pub struct Pause<'info> {
+    pub signer: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = (
+            program_config.guardian == signer.key() || program_config.admin == signer.key()
+        ) @ BodyDfiError::InvalidAuthority
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+}
+
+/// Unpause through governance account context
+#[derive(Accounts)]
+pub struct ExecuteUnpause<'info> {
+    pub executor: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"proposal", &proposal.id.to_le_bytes()],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+    
+    #[account(
+        seeds = [b"parameter-change".as_ref(), &proposal.id.to_le_bytes()],
+        bump
+    )]
+    pub parameter_change: Account<'info, ParameterChange>,
+    
+    #[account(
+        mut,
+        seeds = [b"program-config"],
+        bump = program_config.bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+}
+
+/// Program-wide configuration account
+#[account]
+pub struct ProgramConfig {
+    /// Admin allowed to change the configuration
+    pub admin: Pubkey,
+    
+    /// Admin proposed by the current admin, pending acceptance
+    pub pending_admin: Pubkey,
+    
+    /// Wallet owning the platform fee token accounts
+    pub treasury: Pubkey,
+    
+    /// Token account receiving the token holder share of fees
+    pub holder_rewards_vault: Pubkey,
+    
+    /// Bit set of enabled features
+    pub feature_flags: u64,
+    
+    /// Guardian allowed to pause subsystems
+    pub guardian: Pubkey,
+    
+    /// Bit set of paused subsystems
+    pub pause_flags: u8,
+    
+    /// BodyDFi mint whose balances carry voting power; set once
+    pub governance_mint: Pubkey,
+    
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl ProgramConfig {
+    pub const LEN: usize = 32 + 32 + 32 + 32 + 8 + 32 + 1 + 32 + 1;
+    
+    /// Whether a feature flag is enabled
+    pub fn is_feature_enabled(&self, flag: u64) -> bool {
+        self.feature_flags & flag != 0
+    }
+    
+    /// Whether a subsystem is paused
+    pub fn is_paused(&self, subsystem: u8) -> bool {
+        self.pause_flags & subsystem != 0
+    }
+}
+
+// Import error code
+use crate::errors::BodyDfiError;
+use crate::token::token_state::TokenMint;
\ No newline at end of file
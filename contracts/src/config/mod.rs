@@ -0,0 +1,5 @@
+pub mod config_operations;
+pub mod config_state;
+
+pub use config_operations::*;
+pub use config_state::*;
\ No newline at end of file
@@ -0,0 +1,232 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use crate::errors::BodyDfiError;
+use crate::data_marketplace::data_state::*;
+use crate::data_marketplace::license_operations::check_license_access;
+
+/// Apply the access rules to deserialized accounts; shared by the program and off-chain gateways
+pub fn check_data_access(
+    data_access: &DataAccess,
+    data_listing: &DataListing,
+    current_time: i64,
+) -> Result<AccessInfo> {
+    require!(
+        data_access.listing_id == data_listing.listing_id && data_listing.is_active,
+        BodyDfiError::InvalidDataListing
+    );
+    require!(data_access.is_valid, BodyDfiError::AccessRevoked);
+    require!(current_time < data_access.expires_at, BodyDfiError::AccessExpired);
+    
+    Ok(AccessInfo {
+        buyer: data_access.buyer,
+        provider: data_listing.provider,
+        listing_id: data_access.listing_id.clone(),
+        data_types: data_listing.data_types.clone(),
+        expires_at: data_access.expires_at,
+        remaining_seconds: data_access.expires_at - current_time,
+    })
+}
+
+/// Apply the access rules to a license and the token account holding it
+pub fn check_license_data_access(
+    license_record: &LicenseRecord,
+    holder_license_account: &TokenAccount,
+    data_listing: &DataListing,
+    current_time: i64,
+) -> Result<AccessInfo> {
+    require!(
+        license_record.listing_id == data_listing.listing_id && data_listing.is_active,
+        BodyDfiError::InvalidDataListing
+    );
+    check_license_access(
+        license_record,
+        holder_license_account,
+        holder_license_account.owner,
+        current_time,
+    )?;
+    
+    Ok(AccessInfo {
+        buyer: holder_license_account.owner,
+        provider: data_listing.provider,
+        listing_id: license_record.listing_id.clone(),
+        data_types: data_listing.data_types.clone(),
+        expires_at: license_record.expires_at,
+        remaining_seconds: license_record.expires_at - current_time,
+    })
+}
+
+/// Verify data access, returning the access details as return data
+pub fn verify_data_access(ctx: Context<VerifyDataAccess>) -> Result<AccessInfo> {
+    check_data_access(
+        &ctx.accounts.data_access,
+        &ctx.accounts.data_listing,
+        ctx.accounts.clock.unix_timestamp,
+    )
+}
+
+/// Verify access held through a license token, returning the access details as return data
+pub fn verify_license_access(ctx: Context<VerifyLicenseAccess>) -> Result<AccessInfo> {
+    check_license_data_access(
+        &ctx.accounts.license_record,
+        &ctx.accounts.holder_license_account,
+        &ctx.accounts.data_listing,
+        ctx.accounts.clock.unix_timestamp,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::program_pack::Pack;
+    use anchor_spl::token::spl_token::state::{Account as SplTokenAccount, AccountState};
+    
+    fn data_listing() -> DataListing {
+        DataListing {
+            provider: Pubkey::new_from_array([1; 32]),
+            listing_id: String::from("listing"),
+            data_types: vec![DATA_TYPE_MOTION, DATA_TYPE_BIOMETRIC],
+            price_per_access: 100,
+            access_period: 1_000,
+            description: String::new(),
+            purchase_count: 1,
+            created_at: 0,
+            is_active: true,
+            latest_access_expiry: 2_000,
+            transferable_license: false,
+            royalty_bps: 0,
+            exclusive_until: 0,
+            payment_mint: Pubkey::default(),
+            is_native_sol: false,
+            listing_version: 0,
+            is_closed: false,
+        }
+    }
+    
+    fn data_access() -> DataAccess {
+        DataAccess {
+            buyer: Pubkey::new_from_array([2; 32]),
+            listing_id: String::from("listing"),
+            purchased_at: 1_000,
+            expires_at: 2_000,
+            amount_paid: 100,
+            is_valid: true,
+            renewal_count: 0,
+        }
+    }
+    
+    fn license_record() -> LicenseRecord {
+        LicenseRecord {
+            listing_id: String::from("listing"),
+            license_index: 0,
+            license_mint: Pubkey::new_from_array([3; 32]),
+            purchased_at: 1_000,
+            expires_at: 2_000,
+            amount_paid: 100,
+            is_valid: true,
+            bump: 0,
+        }
+    }
+    
+    fn license_account(owner: Pubkey, mint: Pubkey, amount: u64) -> TokenAccount {
+        let mut data = [0u8; SplTokenAccount::LEN];
+        SplTokenAccount::pack(
+            SplTokenAccount {
+                mint,
+                owner,
+                amount,
+                state: AccountState::Frozen,
+                ..SplTokenAccount::default()
+            },
+            &mut data,
+        )
+        .unwrap();
+        TokenAccount::try_deserialize(&mut &data[..]).unwrap()
+    }
+    
+    #[test]
+    fn valid_access_returns_details() {
+        let info = check_data_access(&data_access(), &data_listing(), 1_500).unwrap();
+        assert_eq!(
+            info,
+            AccessInfo {
+                buyer: Pubkey::new_from_array([2; 32]),
+                provider: Pubkey::new_from_array([1; 32]),
+                listing_id: String::from("listing"),
+                data_types: vec![DATA_TYPE_MOTION, DATA_TYPE_BIOMETRIC],
+                expires_at: 2_000,
+                remaining_seconds: 500,
+            }
+        );
+    }
+    
+    #[test]
+    fn access_expires_at_expiry() {
+        assert!(check_data_access(&data_access(), &data_listing(), 1_999).is_ok());
+        assert_eq!(
+            check_data_access(&data_access(), &data_listing(), 2_000).unwrap_err(),
+            BodyDfiError::AccessExpired.into()
+        );
+    }
+    
+    #[test]
+    fn revoked_access_is_rejected() {
+        let mut data_access = data_access();
+        data_access.is_valid = false;
+        assert_eq!(
+            check_data_access(&data_access, &data_listing(), 1_500).unwrap_err(),
+            BodyDfiError::AccessRevoked.into()
+        );
+    }
+    
+    #[test]
+    fn inactive_or_mismatched_listing_is_rejected() {
+        let mut inactive_listing = data_listing();
+        inactive_listing.is_active = false;
+        assert_eq!(
+            check_data_access(&data_access(), &inactive_listing, 1_500).unwrap_err(),
+            BodyDfiError::InvalidDataListing.into()
+        );
+        
+        let mut other_listing = data_listing();
+        other_listing.listing_id = String::from("other");
+        assert_eq!(
+            check_data_access(&data_access(), &other_listing, 1_500).unwrap_err(),
+            BodyDfiError::InvalidDataListing.into()
+        );
+    }
+    
+    #[test]
+    fn license_holder_gets_access() {
+        let holder = Pubkey::new_from_array([4; 32]);
+        let license_record = license_record();
+        let account = license_account(holder, license_record.license_mint, 1);
+        
+        let info = check_license_data_access(&license_record, &account, &data_listing(), 1_500).unwrap();
+        assert_eq!(info.buyer, holder);
+        assert_eq!(info.remaining_seconds, 500);
+    }
+    
+    #[test]
+    fn license_checks_token_and_record() {
+        let holder = Pubkey::new_from_array([4; 32]);
+        let license_record = license_record();
+        
+        let empty_account = license_account(holder, license_record.license_mint, 0);
+        assert_eq!(
+            check_license_data_access(&license_record, &empty_account, &data_listing(), 1_500).unwrap_err(),
+            BodyDfiError::InvalidLicense.into()
+        );
+        
+        let other_mint_account = license_account(holder, Pubkey::new_from_array([5; 32]), 1);
+        assert_eq!(
+            check_license_data_access(&license_record, &other_mint_account, &data_listing(), 1_500).unwrap_err(),
+            BodyDfiError::InvalidLicense.into()
+        );
+        
+        let account = license_account(holder, license_record.license_mint, 1);
+        assert_eq!(
+            check_license_data_access(&license_record, &account, &data_listing(), 2_000).unwrap_err(),
+            BodyDfiError::AccessExpired.into()
+        );
+    }
+}
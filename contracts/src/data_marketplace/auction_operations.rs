@@ -0,0 +1,444 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer};
+use crate::errors::BodyDfiError;
+use crate::data_marketplace::data_state::*;
+use crate::data_marketplace::marketplace_operations::{
+    calculate_fee_split, holder_fee_destination, transfer_fee_split, FeeSplit,
+};
+
+/// Time after an English auction ends before an unsettled winning bid can be refunded (7 days)
+pub const AUCTION_SETTLEMENT_WINDOW: i64 = 7 * 24 * 60 * 60;
+
+/// Create an English or Dutch auction for exclusive access to a listing
+#[allow(clippy::too_many_arguments)]
+pub fn create_auction(
+    ctx: Context<CreateAuction>,
+    auction_id: u64,
+    kind: u8,
+    start_price: u64,
+    end_price: u64,
+    min_increment: u64,
+    duration: i64,
+    extension_window: i64,
+) -> Result<()> {
+    // Validate input
+    require!(duration > 0, BodyDfiError::InvalidAuction);
+    if kind == AuctionKind::English as u8 {
+        require!(start_price > 0, BodyDfiError::InvalidAuction);
+        require!(min_increment > 0, BodyDfiError::InvalidAuction);
+        require!(extension_window >= 0, BodyDfiError::InvalidAuction);
+    } else if kind == AuctionKind::Dutch as u8 {
+        require!(
+            end_price > 0 && start_price > end_price,
+            BodyDfiError::InvalidAuction
+        );
+    } else {
+        return err!(BodyDfiError::InvalidAuction);
+    }
+    
+    let data_listing = &mut ctx.accounts.data_listing;
+    let auction = &mut ctx.accounts.auction;
+    let current_time = ctx.accounts.clock.unix_timestamp;
+    
+    // One auction or exclusivity period at a time
+    require!(current_time >= data_listing.exclusive_until, BodyDfiError::InvalidAuction);
+    
+    // The winner's access is only exclusive if no one else still holds access
+    require!(
+        current_time >= data_listing.latest_access_expiry,
+        BodyDfiError::ListingHasActiveAccess
+    );
+    
+    // Close fixed-price sales until the auction settles
+    data_listing.exclusive_until = i64::MAX;
+    
+    // Initialize auction
+    auction.provider = data_listing.provider;
+    auction.listing_id = data_listing.listing_id.clone();
+    auction.auction_id = auction_id;
+    auction.kind = kind;
+    auction.payment_mint = ctx.accounts.payment_mint.key();
+    auction.start_price = start_price;
+    auction.end_price = if kind == AuctionKind::Dutch as u8 { end_price } else { 0 };
+    auction.min_increment = if kind == AuctionKind::English as u8 { min_increment } else { 0 };
+    auction.extension_window = if kind == AuctionKind::English as u8 { extension_window } else { 0 };
+    auction.start_time = current_time;
+    auction.end_time = current_time.checked_add(duration).unwrap();
+    auction.highest_bidder = Pubkey::default();
+    auction.highest_bid = 0;
+    auction.vault = ctx.accounts.auction_vault.key();
+    auction.settled = false;
+    auction.bump = ctx.bumps.auction;
+    
+    // Emit event
+    emit!(AuctionCreatedEvent {
+        provider: auction.provider,
+        listing_id: auction.listing_id.clone(),
+        auction_id,
+        kind,
+        start_price,
+        end_price: auction.end_price,
+        end_time: auction.end_time,
+    });
+    
+    Ok(())
+}
+
+/// Place an escrowed bid on an English auction, refunding the outbid bidder
+pub fn place_bid(ctx: Context<PlaceBid>, amount: u64) -> Result<()> {
+    let auction = &ctx.accounts.auction;
+    let current_time = ctx.accounts.clock.unix_timestamp;
+    
+    require!(auction.kind == AuctionKind::English as u8, BodyDfiError::InvalidAuction);
+    require!(!auction.settled, BodyDfiError::AuctionSettled);
+    require!(current_time < auction.end_time, BodyDfiError::AuctionEnded);
+    
+    let min_bid = if auction.highest_bid == 0 {
+        auction.start_price
+    } else {
+        auction.highest_bid.checked_add(auction.min_increment).unwrap()
+    };
+    require!(amount >= min_bid, BodyDfiError::BidTooLow);
+    
+    // A revoked buyer cannot win access back through an auction
+    let bidder_data_access = &ctx.accounts.bidder_data_access;
+    if !bidder_data_access.data_is_empty() {
+        let data = bidder_data_access.try_borrow_data()?;
+        let bidder_access = DataAccess::try_deserialize(&mut &data[..])?;
+        require!(bidder_access.is_valid, BodyDfiError::AccessRevoked);
+    }
+    
+    // Escrow the new bid
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.bidder_token_account.to_account_info(),
+        to: ctx.accounts.auction_vault.to_account_info(),
+        authority: ctx.accounts.bidder.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    
+    token::transfer(cpi_ctx, amount)?;
+    
+    // Refund the previous highest bid
+    if auction.highest_bid > 0 {
+        let previous_bidder_token_account = ctx
+            .accounts
+            .previous_bidder_token_account
+            .as_ref()
+            .ok_or(BodyDfiError::InvalidTokenAccount)?;
+    
+        let listing_id = auction.listing_id.clone();
+        let auction_id_bytes = auction.auction_id.to_le_bytes();
+        let seeds = &[
+            b"auction".as_ref(),
+            listing_id.as_bytes(),
+            auction_id_bytes.as_ref(),
+            &[auction.bump],
+        ];
+        let signer = &[&seeds[..]];
+    
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.auction_vault.to_account_info(),
+            to: previous_bidder_token_account.to_account_info(),
+            authority: ctx.accounts.auction.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer,
+        );
+    
+        token::transfer(cpi_ctx, auction.highest_bid)?;
+    }
+    
+    let auction = &mut ctx.accounts.auction;
+    auction.highest_bidder = ctx.accounts.bidder.key();
+    auction.highest_bid = amount;
+    
+    // Extend the auction when a bid lands inside the anti-sniping window
+    if auction.end_time - current_time < auction.extension_window {
+        auction.end_time = current_time + auction.extension_window;
+    }
+    
+    // Emit event
+    emit!(BidPlacedEvent {
+        bidder: auction.highest_bidder,
+        listing_id: auction.listing_id.clone(),
+        auction_id: auction.auction_id,
+        amount,
+        end_time: auction.end_time,
+    });
+    
+    Ok(())
+}
+
+/// Settle an ended English auction, paying the provider and granting the winner access
+pub fn settle_auction(ctx: Context<SettleAuction>) -> Result<()> {
+    let auction = &ctx.accounts.auction;
+    let current_time = ctx.accounts.clock.unix_timestamp;
+    
+    require!(auction.kind == AuctionKind::English as u8, BodyDfiError::InvalidAuction);
+    require!(!auction.settled, BodyDfiError::AuctionSettled);
+    require!(current_time >= auction.end_time, BodyDfiError::AuctionNotEnded);
+    require!(auction.highest_bid > 0, BodyDfiError::InvalidAuction);
+    
+    // Pay out the winning bid with the normal fee split
+    let amount = auction.highest_bid;
+    let fee_split = calculate_fee_split(amount, &ctx.accounts.marketplace_config)?;
+    
+    let listing_id = auction.listing_id.clone();
+    let auction_id_bytes = auction.auction_id.to_le_bytes();
+    let seeds = &[
+        b"auction".as_ref(),
+        listing_id.as_bytes(),
+        auction_id_bytes.as_ref(),
+        &[auction.bump],
+    ];
+    let signer = &[&seeds[..]];
+    
+    transfer_fee_split(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.auction_vault.to_account_info(),
+        ctx.accounts.auction.to_account_info(),
+        ctx.accounts.provider_token_account.to_account_info(),
+        ctx.accounts.treasury_token_account.to_account_info(),
+        holder_fee_destination(&ctx.accounts.holder_rewards_vault, &ctx.accounts.treasury_token_account)?,
+        &fee_split,
+        signer,
+    )?;
+    
+    let auction = &mut ctx.accounts.auction;
+    auction.settled = true;
+    
+    grant_auction_access(
+        auction,
+        &mut ctx.accounts.data_listing,
+        &mut ctx.accounts.data_access,
+        &mut ctx.accounts.data_provider,
+        &fee_split,
+        amount,
+        current_time,
+    )
+}
+
+/// Buy a Dutch auction at its current decayed price
+pub fn buy_dutch_auction(ctx: Context<BuyDutchAuction>) -> Result<()> {
+    let auction = &ctx.accounts.auction;
+    let current_time = ctx.accounts.clock.unix_timestamp;
+    
+    require!(auction.kind == AuctionKind::Dutch as u8, BodyDfiError::InvalidAuction);
+    require!(!auction.settled, BodyDfiError::AuctionSettled);
+    require!(current_time < auction.end_time, BodyDfiError::AuctionEnded);
+    
+    // Pay the current price with the normal fee split
+    let amount = auction.dutch_price_at(current_time);
+    let fee_split = calculate_fee_split(amount, &ctx.accounts.marketplace_config)?;
+    
+    transfer_fee_split(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.buyer_token_account.to_account_info(),
+        ctx.accounts.buyer.to_account_info(),
+        ctx.accounts.provider_token_account.to_account_info(),
+        ctx.accounts.treasury_token_account.to_account_info(),
+        holder_fee_destination(&ctx.accounts.holder_rewards_vault, &ctx.accounts.treasury_token_account)?,
+        &fee_split,
+        &[],
+    )?;
+    
+    let auction = &mut ctx.accounts.auction;
+    auction.highest_bidder = ctx.accounts.buyer.key();
+    auction.highest_bid = amount;
+    auction.settled = true;
+    
+    grant_auction_access(
+        auction,
+        &mut ctx.accounts.data_listing,
+        &mut ctx.accounts.data_access,
+        &mut ctx.accounts.data_provider,
+        &fee_split,
+        amount,
+        current_time,
+    )
+}
+
+/// Cancel an auction that has no bids or has not sold
+pub fn cancel_auction(ctx: Context<CancelAuction>) -> Result<()> {
+    let auction = &mut ctx.accounts.auction;
+    
+    require!(!auction.settled, BodyDfiError::AuctionSettled);
+    require!(auction.highest_bid == 0, BodyDfiError::InvalidAuction);
+    
+    auction.settled = true;
+    
+    // Reopen fixed-price sales
+    ctx.accounts.data_listing.exclusive_until = 0;
+    
+    // Emit event
+    emit!(AuctionCancelledEvent {
+        provider: auction.provider,
+        listing_id: auction.listing_id.clone(),
+        auction_id: auction.auction_id,
+    });
+    
+    Ok(())
+}
+
+/// Refund the winning bid of an English auction that was not settled in time
+pub fn refund_auction_bid(ctx: Context<RefundAuctionBid>) -> Result<()> {
+    let auction = &ctx.accounts.auction;
+    let current_time = ctx.accounts.clock.unix_timestamp;
+    
+    require!(auction.kind == AuctionKind::English as u8, BodyDfiError::InvalidAuction);
+    require!(!auction.settled, BodyDfiError::AuctionSettled);
+    require!(auction.highest_bid > 0, BodyDfiError::InvalidAuction);
+    require!(
+        current_time >= auction.end_time.saturating_add(AUCTION_SETTLEMENT_WINDOW),
+        BodyDfiError::AuctionSettlementOpen
+    );
+    
+    // Return the escrowed bid to the highest bidder
+    let amount = auction.highest_bid;
+    let listing_id = auction.listing_id.clone();
+    let auction_id_bytes = auction.auction_id.to_le_bytes();
+    let seeds = &[
+        b"auction".as_ref(),
+        listing_id.as_bytes(),
+        auction_id_bytes.as_ref(),
+        &[auction.bump],
+    ];
+    let signer = &[&seeds[..]];
+    
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.auction_vault.to_account_info(),
+        to: ctx.accounts.bidder_token_account.to_account_info(),
+        authority: ctx.accounts.auction.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer,
+    );
+    
+    token::transfer(cpi_ctx, amount)?;
+    
+    let auction = &mut ctx.accounts.auction;
+    auction.settled = true;
+    
+    // Reopen fixed-price sales
+    ctx.accounts.data_listing.exclusive_until = 0;
+    
+    // Emit event
+    emit!(AuctionBidRefundedEvent {
+        bidder: auction.highest_bidder,
+        listing_id: auction.listing_id.clone(),
+        auction_id: auction.auction_id,
+        amount,
+    });
+    
+    Ok(())
+}
+
+/// Grant the auction winner access to the listing, extending access it already holds, and update stats
+fn grant_auction_access(
+    auction: &Auction,
+    data_listing: &mut DataListing,
+    data_access: &mut DataAccess,
+    data_provider: &mut DataProvider,
+    fee_split: &FeeSplit,
+    amount: u64,
+    current_time: i64,
+) -> Result<()> {
+    if data_access.buyer == Pubkey::default() {
+        data_access.buyer = auction.highest_bidder;
+        data_access.listing_id = auction.listing_id.clone();
+        data_access.purchased_at = current_time;
+        data_access.expires_at = current_time + data_listing.access_period as i64;
+        data_access.amount_paid = amount;
+        data_access.is_valid = true;
+        data_access.renewal_count = 0;
+    } else {
+        // Past buyers keep their account; extend from the later of now and the current expiry
+        require!(data_access.is_valid, BodyDfiError::AccessRevoked);
+        let renewed_from = current_time.max(data_access.expires_at);
+        data_access.expires_at = renewed_from + data_listing.access_period as i64;
+        data_access.amount_paid = data_access.amount_paid.checked_add(amount).unwrap();
+        data_access.renewal_count = data_access.renewal_count.checked_add(1).unwrap();
+    }
+    
+    // The winner holds exclusive access for its full period
+    data_listing.exclusive_until = data_access.expires_at;
+    
+    // Update listing stats
+    data_listing.latest_access_expiry = data_listing.latest_access_expiry.max(data_access.expires_at);
+    data_listing.purchase_count = data_listing.purchase_count.checked_add(1).unwrap();
+    
+    // Update provider stats
+    data_provider.total_rewards = data_provider.total_rewards.checked_add(fee_split.provider_amount).unwrap();
+    
+    // Emit event
+    emit!(AuctionSettledEvent {
+        winner: auction.highest_bidder,
+        provider: auction.provider,
+        listing_id: auction.listing_id.clone(),
+        auction_id: auction.auction_id,
+        amount_paid: amount,
+        platform_fee: fee_split.platform_fee,
+        token_holder_fee: fee_split.token_holder_fee,
+        provider_amount: fee_split.provider_amount,
+        expires_at: data_access.expires_at,
+    });
+    
+    Ok(())
+}
+
+/// Event emitted when an auction is created
+#[event]
+pub struct AuctionCreatedEvent {
+    pub provider: Pubkey,
+    pub listing_id: String,
+    pub auction_id: u64,
+    pub kind: u8,
+    pub start_price: u64,
+    pub end_price: u64,
+    pub end_time: i64,
+}
+
+/// Event emitted when a bid is placed
+#[event]
+pub struct BidPlacedEvent {
+    pub bidder: Pubkey,
+    pub listing_id: String,
+    pub auction_id: u64,
+    pub amount: u64,
+    pub end_time: i64,
+}
+
+/// Event emitted when an auction settles into data access
+#[event]
+pub struct AuctionSettledEvent {
+    pub winner: Pubkey,
+    pub provider: Pubkey,
+    pub listing_id: String,
+    pub auction_id: u64,
+    pub amount_paid: u64,
+    pub platform_fee: u64,
+    pub token_holder_fee: u64,
+    pub provider_amount: u64,
+    pub expires_at: i64,
+}
+
+/// Event emitted when an auction is cancelled
+#[event]
+pub struct AuctionCancelledEvent {
+    pub provider: Pubkey,
+    pub listing_id: String,
+    pub auction_id: u64,
+}
+
+/// Event emitted when an unsettled winning bid is refunded
+#[event]
+pub struct AuctionBidRefundedEvent {
+    pub bidder: Pubkey,
+    pub listing_id: String,
+    pub auction_id: u64,
+    pub amount: u64,
+}
@@ -0,0 +1,265 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer};
+use crate::errors::BodyDfiError;
+use crate::data_marketplace::data_state::*;
+use crate::data_marketplace::marketplace_operations::{calculate_fee_split, holder_fee_destination, transfer_fee_split};
+
+/// Post a bounty, escrowing payment for up to `max_providers` providers
+#[allow(clippy::too_many_arguments)]
+pub fn create_bounty(
+    ctx: Context<CreateBounty>,
+    bounty_id: String,
+    data_types: Vec<u8>,
+    device_types: Vec<u8>,
+    min_reputation_score: u16,
+    price_per_provider: u64,
+    max_providers: u64,
+    access_period: u64,
+    duration: i64,
+) -> Result<()> {
+    // Validate input
+    require!(bounty_id.len() <= Bounty::MAX_BOUNTY_ID_LEN, BodyDfiError::InputTooLong);
+    require!(!data_types.is_empty(), BodyDfiError::InvalidDataType);
+    require!(data_types.len() <= Bounty::MAX_DATA_TYPES, BodyDfiError::InputTooLong);
+    require!(!device_types.is_empty(), BodyDfiError::InvalidDeviceType);
+    require!(device_types.len() <= Bounty::MAX_DEVICE_TYPES, BodyDfiError::InputTooLong);
+    require!(price_per_provider > 0, BodyDfiError::InvalidBounty);
+    require!(max_providers > 0, BodyDfiError::InvalidBounty);
+    require!(access_period > 0, BodyDfiError::InvalidBounty);
+    require!(duration > 0, BodyDfiError::InvalidBounty);
+    
+    for data_type in data_types.iter() {
+        require!(
+            *data_type <= DATA_TYPE_MEDICAL,
+            BodyDfiError::InvalidDataType
+        );
+    }
+    for device_type in device_types.iter() {
+        require!(
+            *device_type <= DEVICE_TYPE_MEDICAL,
+            BodyDfiError::InvalidDeviceType
+        );
+    }
+    
+    let total_amount = price_per_provider
+        .checked_mul(max_providers)
+        .ok_or(BodyDfiError::InvalidBounty)?;
+    
+    // Escrow the full bounty up front
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.buyer_token_account.to_account_info(),
+        to: ctx.accounts.bounty_vault.to_account_info(),
+        authority: ctx.accounts.buyer.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    
+    token::transfer(cpi_ctx, total_amount)?;
+    
+    let bounty = &mut ctx.accounts.bounty;
+    let current_time = ctx.accounts.clock.unix_timestamp;
+    
+    // Initialize bounty
+    bounty.buyer = ctx.accounts.buyer.key();
+    bounty.bounty_id = bounty_id;
+    bounty.data_types = data_types;
+    bounty.device_types = device_types;
+    bounty.min_reputation_score = min_reputation_score;
+    bounty.price_per_provider = price_per_provider;
+    bounty.max_providers = max_providers;
+    bounty.accepted_count = 0;
+    bounty.access_period = access_period;
+    bounty.expires_at = current_time.checked_add(duration).unwrap();
+    bounty.vault = ctx.accounts.bounty_vault.key();
+    bounty.is_active = true;
+    bounty.bump = ctx.bumps.bounty;
+    
+    // Emit event
+    emit!(BountyCreatedEvent {
+        buyer: bounty.buyer,
+        bounty_id: bounty.bounty_id.clone(),
+        price_per_provider,
+        max_providers,
+        total_amount,
+        expires_at: bounty.expires_at,
+    });
+    
+    Ok(())
+}
+
+/// Accept a bounty, granting the buyer access to a listing and paying the provider from escrow
+pub fn accept_bounty(ctx: Context<AcceptBounty>) -> Result<()> {
+    let bounty = &ctx.accounts.bounty;
+    let data_provider = &ctx.accounts.data_provider;
+    let data_listing = &ctx.accounts.data_listing;
+    let current_time = ctx.accounts.clock.unix_timestamp;
+    
+    // Validate bounty state
+    require!(bounty.is_active, BodyDfiError::InvalidBounty);
+    require!(current_time < bounty.expires_at, BodyDfiError::BountyExpired);
+    require!(bounty.accepted_count < bounty.max_providers, BodyDfiError::BountyFilled);
+    
+    // Validate the provider and listing meet the bounty's requirements
+    require!(
+        bounty.device_types.contains(&data_provider.device_type)
+            && data_provider.reputation_score >= bounty.min_reputation_score
+            && data_listing.is_open_for_sale(current_time)
+            && bounty.data_types.iter().all(|data_type| data_listing.data_types.contains(data_type)),
+        BodyDfiError::BountyRequirementsNotMet
+    );
+    
+    // A buyer who already holds access to the listing has it extended, unless it was revoked
+    let is_renewal = ctx.accounts.data_access.buyer != Pubkey::default();
+    if is_renewal {
+        require!(ctx.accounts.data_access.is_valid, BodyDfiError::AccessRevoked);
+    }
+    
+    // Pay the provider's share out of the escrow
+    let price = bounty.price_per_provider;
+    let fee_split = calculate_fee_split(price, &ctx.accounts.marketplace_config)?;
+    
+    let bounty_id = bounty.bounty_id.clone();
+    let seeds = &[
+        b"bounty".as_ref(),
+        bounty_id.as_bytes(),
+        &[bounty.bump],
+    ];
+    let signer = &[&seeds[..]];
+    
+    transfer_fee_split(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.bounty_vault.to_account_info(),
+        ctx.accounts.bounty.to_account_info(),
+        ctx.accounts.provider_token_account.to_account_info(),
+        ctx.accounts.treasury_token_account.to_account_info(),
+        holder_fee_destination(&ctx.accounts.holder_rewards_vault, &ctx.accounts.treasury_token_account)?,
+        &fee_split,
+        signer,
+    )?;
+    
+    // Grant the buyer access to the listing
+    let bounty = &mut ctx.accounts.bounty;
+    let data_listing = &mut ctx.accounts.data_listing;
+    let data_access = &mut ctx.accounts.data_access;
+    if is_renewal {
+        // Extend from the later of now and the current expiry
+        let renewed_from = current_time.max(data_access.expires_at);
+        data_access.expires_at = renewed_from + bounty.access_period as i64;
+        data_access.amount_paid = data_access.amount_paid.checked_add(price).unwrap();
+        data_access.renewal_count = data_access.renewal_count.checked_add(1).unwrap();
+    } else {
+        data_access.buyer = bounty.buyer;
+        data_access.listing_id = data_listing.listing_id.clone();
+        data_access.purchased_at = current_time;
+        data_access.expires_at = current_time + bounty.access_period as i64;
+        data_access.amount_paid = price;
+        data_access.is_valid = true;
+        data_access.renewal_count = 0;
+    }
+    data_listing.latest_access_expiry = data_listing.latest_access_expiry.max(data_access.expires_at);
+    data_listing.purchase_count = data_listing.purchase_count.checked_add(1).unwrap();
+    
+    // Record the acceptance
+    let bounty_acceptance = &mut ctx.accounts.bounty_acceptance;
+    bounty_acceptance.bounty = bounty.key();
+    bounty_acceptance.provider = ctx.accounts.provider.key();
+    bounty_acceptance.accepted_at = current_time;
+    bounty_acceptance.amount_paid = price;
+    
+    bounty.accepted_count = bounty.accepted_count.checked_add(1).unwrap();
+    
+    // Update provider stats
+    let data_provider = &mut ctx.accounts.data_provider;
+    data_provider.total_rewards = data_provider.total_rewards.checked_add(fee_split.provider_amount).unwrap();
+    
+    // Emit event
+    emit!(BountyAcceptedEvent {
+        buyer: bounty.buyer,
+        provider: data_provider.authority,
+        bounty_id: bounty.bounty_id.clone(),
+        listing_id: data_listing.listing_id.clone(),
+        amount_paid: price,
+        provider_amount: fee_split.provider_amount,
+        expires_at: data_access.expires_at,
+    });
+    
+    Ok(())
+}
+
+/// Reclaim the unspent escrow of an expired bounty
+pub fn reclaim_bounty(ctx: Context<ReclaimBounty>) -> Result<()> {
+    let bounty = &ctx.accounts.bounty;
+    let current_time = ctx.accounts.clock.unix_timestamp;
+    
+    require!(bounty.is_active, BodyDfiError::InvalidBounty);
+    require!(current_time >= bounty.expires_at, BodyDfiError::BountyNotExpired);
+    
+    let amount = ctx.accounts.bounty_vault.amount;
+    
+    if amount > 0 {
+        let bounty_id = bounty.bounty_id.clone();
+        let seeds = &[
+            b"bounty".as_ref(),
+            bounty_id.as_bytes(),
+            &[bounty.bump],
+        ];
+        let signer = &[&seeds[..]];
+    
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.bounty_vault.to_account_info(),
+            to: ctx.accounts.buyer_token_account.to_account_info(),
+            authority: ctx.accounts.bounty.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer,
+        );
+    
+        token::transfer(cpi_ctx, amount)?;
+    }
+    
+    let bounty = &mut ctx.accounts.bounty;
+    bounty.is_active = false;
+    
+    // Emit event
+    emit!(BountyReclaimedEvent {
+        buyer: bounty.buyer,
+        bounty_id: bounty.bounty_id.clone(),
+        amount,
+        accepted_count: bounty.accepted_count,
+    });
+    
+    Ok(())
+}
+
+/// Event emitted when a bounty is posted
+#[event]
+pub struct BountyCreatedEvent {
+    pub buyer: Pubkey,
+    pub bounty_id: String,
+    pub price_per_provider: u64,
+    pub max_providers: u64,
+    pub total_amount: u64,
+    pub expires_at: i64,
+}
+
+/// Event emitted when a provider accepts a bounty
+#[event]
+pub struct BountyAcceptedEvent {
+    pub buyer: Pubkey,
+    pub provider: Pubkey,
+    pub bounty_id: String,
+    pub listing_id: String,
+    pub amount_paid: u64,
+    pub provider_amount: u64,
+    pub expires_at: i64,
+}
+
+/// Event emitted when a buyer reclaims unspent bounty escrow
+#[event]
+pub struct BountyReclaimedEvent {
+    pub buyer: Pubkey,
+    pub bounty_id: String,
+    pub amount: u64,
+    pub accepted_count: u64,
+}
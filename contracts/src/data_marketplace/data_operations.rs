@@ -43,6 +43,7 @@ pub fn submit_data(
     data_submission.metadata = metadata;
     data_submission.quality_score = 0; // Will be set by validators later
     data_submission.is_validated = false;
+    data_submission.is_rewarded = false;
     
     // Update provider stats
     data_provider.submission_count = data_provider.submission_count.checked_add(1).unwrap();
@@ -69,11 +70,7 @@ pub fn validate_data(
     data_provider: &mut Account<DataProvider>,
     quality_score: u8,
 ) -> Result<()> {
-    // Validate quality score
-    require!(
-        quality_score < 5,
-        BodyDfiError::InvalidDataQualityScore
-    );
+    check_validation(data_submission, quality_score)?;
     
     // Update data submission
     data_submission.quality_score = quality_score;
@@ -85,6 +82,21 @@ pub fn validate_data(
     Ok(())
 }
 
+/// Check that a submission can be scored; each submission is validated once
+/// so provider reputation is only updated once per submission
+fn check_validation(data_submission: &DataSubmission, quality_score: u8) -> Result<()> {
+    require!(
+        !data_submission.is_validated,
+        BodyDfiError::SubmissionAlreadyValidated
+    );
+    require!(
+        quality_score < 5,
+        BodyDfiError::InvalidDataQualityScore
+    );
+    
+    Ok(())
+}
+
 /// Event emitted when data is submitted
 #[event]
 pub struct DataSubmittedEvent {
@@ -92,4 +104,38 @@ pub struct DataSubmittedEvent {
     pub data_hash: String,
     pub data_type: u8,
     pub timestamp: i64,
-} 
\ No newline at end of file
+} 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn submission(is_validated: bool) -> DataSubmission {
+        DataSubmission {
+            provider: Pubkey::new_unique(),
+            data_hash: "hash".to_string(),
+            data_type: DATA_TYPE_MOTION,
+            timestamp: 0,
+            metadata: String::new(),
+            quality_score: 0,
+            is_validated,
+            is_rewarded: false,
+        }
+    }
+
+    #[test]
+    fn unvalidated_submission_accepts_valid_score() {
+        assert!(check_validation(&submission(false), 4).is_ok());
+    }
+
+    #[test]
+    fn rejects_out_of_range_score() {
+        let r = check_validation(&submission(false), 5);
+        assert_eq!(r.unwrap_err(), BodyDfiError::InvalidDataQualityScore.into());
+    }
+
+    #[test]
+    fn rejects_revalidation() {
+        let r = check_validation(&submission(true), 3);
+        assert_eq!(r.unwrap_err(), BodyDfiError::SubmissionAlreadyValidated.into());
+    }
+}
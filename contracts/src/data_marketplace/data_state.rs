@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::TokenAccount;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
 
 /// Device types
 pub const DEVICE_TYPE_SENSOR: u8 = 0;
@@ -15,10 +16,18 @@ pub const DATA_TYPE_MEDICAL: u8 = 4;
 
 /// Register data provider account context
 #[derive(Accounts)]
+#[instruction(user_id: String)]
 pub struct RegisterDataProvider<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
     
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_DATA) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
     #[account(
         init,
         payer = authority,
@@ -33,10 +42,18 @@ pub struct RegisterDataProvider<'info> {
 
 /// Submit data account context
 #[derive(Accounts)]
+#[instruction(data_hash: String)]
 pub struct SubmitData<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
     
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_DATA) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
     #[account(
         mut,
         seeds = [b"data-provider", data_provider.user_id.as_bytes()],
@@ -58,73 +75,2047 @@ pub struct SubmitData<'info> {
     pub clock: Sysvar<'info, Clock>,
 }
 
+/// Validate data submission account context
+#[derive(Accounts)]
+pub struct ValidateData<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_DATA) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    /// Move token mint whose authority acts as the trusted validator
+    #[account(
+        constraint = token_mint.is_move_token @ BodyDfiError::InvalidMint,
+        constraint = token_mint.authority == authority.key() @ BodyDfiError::InvalidAuthority
+    )]
+    pub token_mint: Account<'info, TokenMint>,
+    
+    #[account(
+        mut,
+        seeds = [b"data-provider", data_provider.user_id.as_bytes()],
+        bump
+    )]
+    pub data_provider: Account<'info, DataProvider>,
+    
+    #[account(
+        mut,
+        seeds = [b"data-submission", data_submission.data_hash.as_bytes()],
+        bump,
+        constraint = data_submission.provider == data_provider.authority @ BodyDfiError::InvalidDataProvider
+    )]
+    pub data_submission: Account<'info, DataSubmission>,
+}
+
 /// Create data listing account context
 #[derive(Accounts)]
+#[instruction(listing_id: String)]
 pub struct CreateDataListing<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
     
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_MARKETPLACE) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
     #[account(
         mut,
         seeds = [b"data-provider", data_provider.user_id.as_bytes()],
         bump,
         constraint = data_provider.authority == user.key() @ BodyDfiError::InvalidAuthority
     )]
-    pub data_provider: Account<'info, DataProvider>,
+    pub data_provider: Account<'info, DataProvider>,
+    
+    #[account(
+        init,
+        payer = user,
+        seeds = [b"data-listing", listing_id.as_bytes()],
+        bump,
+        space = 8 + DataListing::LEN
+    )]
+    pub data_listing: Account<'info, DataListing>,
+    
+    /// Allowlist entry of the SPL payment mint; omitted for SOL-priced listings
+    #[account(
+        seeds = [b"payment-mint", allowed_payment_mint.mint.as_ref()],
+        bump = allowed_payment_mint.bump,
+        constraint = allowed_payment_mint.is_allowed @ BodyDfiError::InvalidPaymentMint
+    )]
+    pub allowed_payment_mint: Option<Account<'info, AllowedPaymentMint>>,
+    
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Manage data listing account context
+#[derive(Accounts)]
+pub struct ManageDataListing<'info> {
+    pub provider: Signer<'info>,
+    
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_MARKETPLACE) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"data-listing", data_listing.listing_id.as_bytes()],
+        bump,
+        constraint = data_listing.provider == provider.key() @ BodyDfiError::InvalidAuthority,
+        constraint = !data_listing.is_closed @ BodyDfiError::ListingClosed
+    )]
+    pub data_listing: Account<'info, DataListing>,
+}
+
+/// Close data listing account context
+#[derive(Accounts)]
+pub struct CloseDataListing<'info> {
+    #[account(mut)]
+    pub provider: Signer<'info>,
+    
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_MARKETPLACE) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"data-listing", data_listing.listing_id.as_bytes()],
+        bump,
+        constraint = data_listing.provider == provider.key() @ BodyDfiError::InvalidAuthority,
+        constraint = !data_listing.is_closed @ BodyDfiError::ListingClosed,
+        realloc = 8 + data_listing.tombstone_len(),
+        realloc::payer = provider,
+        realloc::zero = false
+    )]
+    pub data_listing: Account<'info, DataListing>,
+    
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Purchase data access account context
+#[derive(Accounts)]
+pub struct PurchaseDataAccess<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_MARKETPLACE) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(
+        seeds = [b"marketplace-config"],
+        bump = marketplace_config.bump
+    )]
+    pub marketplace_config: Account<'info, MarketplaceConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"data-listing", data_listing.listing_id.as_bytes()],
+        bump
+    )]
+    pub data_listing: Account<'info, DataListing>,
+    
+    #[account(
+        mut,
+        seeds = [b"data-provider", data_provider.user_id.as_bytes()],
+        bump,
+        constraint = data_provider.authority == data_listing.provider @ BodyDfiError::InvalidDataProvider
+    )]
+    pub data_provider: Account<'info, DataProvider>,
+    
+    #[account(
+        init,
+        payer = buyer,
+        seeds = [b"data-access", buyer.key().as_ref(), data_listing.listing_id.as_bytes()],
+        bump,
+        space = 8 + DataAccess::LEN
+    )]
+    pub data_access: Account<'info, DataAccess>,
+    
+    #[account(
+        mut,
+        constraint = buyer_token_account.mint == data_listing.payment_mint @ BodyDfiError::InvalidMint
+    )]
+    pub buyer_token_account: Option<Account<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        constraint = provider_token_account.owner == data_listing.provider @ BodyDfiError::InvalidTokenAccount,
+        constraint = provider_token_account.mint == data_listing.payment_mint @ BodyDfiError::InvalidMint
+    )]
+    pub provider_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Platform treasury token account receiving the platform fee
+    #[account(
+        mut,
+        constraint = treasury_token_account.owner == program_config.treasury @ BodyDfiError::InvalidTokenAccount,
+        constraint = treasury_token_account.mint == data_listing.payment_mint @ BodyDfiError::InvalidMint
+    )]
+    pub treasury_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Vault receiving the token holder fee
+    #[account(
+        mut,
+        address = program_config.holder_rewards_vault @ BodyDfiError::InvalidTokenAccount
+    )]
+    pub holder_rewards_vault: Option<Account<'info, TokenAccount>>,
+    
+    /// CHECK: Provider wallet receiving SOL payments
+    #[account(
+        mut,
+        address = data_listing.provider @ BodyDfiError::InvalidDataProvider
+    )]
+    pub provider_wallet: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: Treasury wallet receiving SOL fees
+    #[account(
+        mut,
+        address = program_config.treasury @ BodyDfiError::InvalidTokenAccount
+    )]
+    pub treasury_wallet: Option<UncheckedAccount<'info>>,
+    
+    pub token_program: Program<'info, anchor_spl::token::Token>,
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Renew data access account context
+#[derive(Accounts)]
+pub struct RenewDataAccess<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_MARKETPLACE) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(
+        seeds = [b"marketplace-config"],
+        bump = marketplace_config.bump
+    )]
+    pub marketplace_config: Account<'info, MarketplaceConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"data-listing", data_listing.listing_id.as_bytes()],
+        bump
+    )]
+    pub data_listing: Account<'info, DataListing>,
+    
+    #[account(
+        mut,
+        seeds = [b"data-provider", data_provider.user_id.as_bytes()],
+        bump,
+        constraint = data_provider.authority == data_listing.provider @ BodyDfiError::InvalidDataProvider
+    )]
+    pub data_provider: Account<'info, DataProvider>,
+    
+    #[account(
+        mut,
+        seeds = [b"data-access", buyer.key().as_ref(), data_listing.listing_id.as_bytes()],
+        bump
+    )]
+    pub data_access: Account<'info, DataAccess>,
+    
+    #[account(
+        mut,
+        constraint = buyer_token_account.mint == data_listing.payment_mint @ BodyDfiError::InvalidMint
+    )]
+    pub buyer_token_account: Option<Account<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        constraint = provider_token_account.owner == data_listing.provider @ BodyDfiError::InvalidTokenAccount,
+        constraint = provider_token_account.mint == data_listing.payment_mint @ BodyDfiError::InvalidMint
+    )]
+    pub provider_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Platform treasury token account receiving the platform fee
+    #[account(
+        mut,
+        constraint = treasury_token_account.owner == program_config.treasury @ BodyDfiError::InvalidTokenAccount,
+        constraint = treasury_token_account.mint == data_listing.payment_mint @ BodyDfiError::InvalidMint
+    )]
+    pub treasury_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Vault receiving the token holder fee
+    #[account(
+        mut,
+        address = program_config.holder_rewards_vault @ BodyDfiError::InvalidTokenAccount
+    )]
+    pub holder_rewards_vault: Option<Account<'info, TokenAccount>>,
+    
+    /// CHECK: Provider wallet receiving SOL payments
+    #[account(
+        mut,
+        address = data_listing.provider @ BodyDfiError::InvalidDataProvider
+    )]
+    pub provider_wallet: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: Treasury wallet receiving SOL fees
+    #[account(
+        mut,
+        address = program_config.treasury @ BodyDfiError::InvalidTokenAccount
+    )]
+    pub treasury_wallet: Option<UncheckedAccount<'info>>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Create subscription account context
+#[derive(Accounts)]
+pub struct CreateSubscription<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_MARKETPLACE) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(
+        seeds = [b"data-listing", data_listing.listing_id.as_bytes()],
+        bump,
+        constraint = !data_listing.is_native_sol @ BodyDfiError::NativeSolNotSupported
+    )]
+    pub data_listing: Account<'info, DataListing>,
+    
+    #[account(
+        seeds = [b"data-access", buyer.key().as_ref(), data_listing.listing_id.as_bytes()],
+        bump
+    )]
+    pub data_access: Account<'info, DataAccess>,
+    
+    #[account(
+        init,
+        payer = buyer,
+        seeds = [b"subscription", data_access.key().as_ref()],
+        bump,
+        space = 8 + Subscription::LEN
+    )]
+    pub subscription: Account<'info, Subscription>,
+    
+    #[account(
+        mut,
+        constraint = buyer_token_account.owner == buyer.key() @ BodyDfiError::InvalidTokenAccount,
+        constraint = buyer_token_account.mint == data_listing.payment_mint @ BodyDfiError::InvalidMint
+    )]
+    pub buyer_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Charge subscription account context
+#[derive(Accounts)]
+pub struct ChargeSubscription<'info> {
+    /// Anyone can crank a due subscription
+    pub cranker: Signer<'info>,
+    
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_MARKETPLACE) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(
+        seeds = [b"marketplace-config"],
+        bump = marketplace_config.bump
+    )]
+    pub marketplace_config: Account<'info, MarketplaceConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"subscription", subscription.data_access.as_ref()],
+        bump = subscription.bump
+    )]
+    pub subscription: Account<'info, Subscription>,
+    
+    #[account(
+        mut,
+        seeds = [b"data-listing", subscription.listing_id.as_bytes()],
+        bump
+    )]
+    pub data_listing: Account<'info, DataListing>,
+    
+    #[account(
+        mut,
+        address = subscription.data_access @ BodyDfiError::InvalidDataListing
+    )]
+    pub data_access: Account<'info, DataAccess>,
+    
+    #[account(
+        mut,
+        seeds = [b"data-provider", data_provider.user_id.as_bytes()],
+        bump,
+        constraint = data_provider.authority == data_listing.provider @ BodyDfiError::InvalidDataProvider
+    )]
+    pub data_provider: Account<'info, DataProvider>,
+    
+    #[account(
+        mut,
+        address = subscription.buyer_token_account @ BodyDfiError::InvalidTokenAccount
+    )]
+    pub buyer_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = provider_token_account.owner == data_listing.provider @ BodyDfiError::InvalidTokenAccount,
+        constraint = provider_token_account.mint == data_listing.payment_mint @ BodyDfiError::InvalidMint
+    )]
+    pub provider_token_account: Account<'info, TokenAccount>,
+    
+    /// Platform treasury token account receiving the platform fee
+    #[account(
+        mut,
+        constraint = treasury_token_account.owner == program_config.treasury @ BodyDfiError::InvalidTokenAccount,
+        constraint = treasury_token_account.mint == data_listing.payment_mint @ BodyDfiError::InvalidMint
+    )]
+    pub treasury_token_account: Account<'info, TokenAccount>,
+    
+    /// Vault receiving the token holder fee
+    #[account(
+        mut,
+        address = program_config.holder_rewards_vault @ BodyDfiError::InvalidTokenAccount
+    )]
+    pub holder_rewards_vault: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Cancel subscription account context
+#[derive(Accounts)]
+pub struct CancelSubscription<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    #[account(
+        mut,
+        close = buyer,
+        seeds = [b"subscription", subscription.data_access.as_ref()],
+        bump = subscription.bump,
+        constraint = subscription.buyer == buyer.key() @ BodyDfiError::InvalidAuthority
+    )]
+    pub subscription: Account<'info, Subscription>,
+    
+    #[account(
+        mut,
+        address = subscription.buyer_token_account @ BodyDfiError::InvalidTokenAccount
+    )]
+    pub buyer_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+/// Create bounty account context
+#[derive(Accounts)]
+#[instruction(bounty_id: String)]
+pub struct CreateBounty<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_MARKETPLACE) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(
+        init,
+        payer = buyer,
+        seeds = [b"bounty", bounty_id.as_bytes()],
+        bump,
+        space = 8 + Bounty::LEN
+    )]
+    pub bounty: Account<'info, Bounty>,
+    
+    #[account(
+        mut,
+        constraint = buyer_token_account.owner == buyer.key() @ BodyDfiError::InvalidTokenAccount
+    )]
+    pub buyer_token_account: Account<'info, TokenAccount>,
+    
+    #[account(address = buyer_token_account.mint @ BodyDfiError::InvalidMint)]
+    pub payment_mint: Account<'info, Mint>,
+    
+    /// Token account escrowing the bounty funds
+    #[account(
+        init,
+        payer = buyer,
+        seeds = [b"bounty-vault", bounty.key().as_ref()],
+        bump,
+        token::mint = payment_mint,
+        token::authority = bounty
+    )]
+    pub bounty_vault: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Accept bounty account context
+#[derive(Accounts)]
+pub struct AcceptBounty<'info> {
+    #[account(mut)]
+    pub provider: Signer<'info>,
+    
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_MARKETPLACE) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(
+        seeds = [b"marketplace-config"],
+        bump = marketplace_config.bump
+    )]
+    pub marketplace_config: Account<'info, MarketplaceConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"bounty", bounty.bounty_id.as_bytes()],
+        bump = bounty.bump
+    )]
+    pub bounty: Account<'info, Bounty>,
+    
+    #[account(
+        mut,
+        seeds = [b"data-provider", data_provider.user_id.as_bytes()],
+        bump,
+        constraint = data_provider.authority == provider.key() @ BodyDfiError::InvalidAuthority
+    )]
+    pub data_provider: Account<'info, DataProvider>,
+    
+    /// Provider listing the buyer is granted access to
+    #[account(
+        mut,
+        seeds = [b"data-listing", data_listing.listing_id.as_bytes()],
+        bump,
+        constraint = data_listing.provider == provider.key() @ BodyDfiError::InvalidAuthority,
+        constraint = !data_listing.is_native_sol @ BodyDfiError::NativeSolNotSupported
+    )]
+    pub data_listing: Account<'info, DataListing>,
+    
+    #[account(
+        init_if_needed,
+        payer = provider,
+        seeds = [b"data-access", bounty.buyer.as_ref(), data_listing.listing_id.as_bytes()],
+        bump,
+        space = 8 + DataAccess::LEN
+    )]
+    pub data_access: Account<'info, DataAccess>,
+    
+    #[account(
+        init,
+        payer = provider,
+        seeds = [b"bounty-acceptance", bounty.key().as_ref(), provider.key().as_ref()],
+        bump,
+        space = 8 + BountyAcceptance::LEN
+    )]
+    pub bounty_acceptance: Account<'info, BountyAcceptance>,
+    
+    #[account(
+        mut,
+        address = bounty.vault @ BodyDfiError::InvalidTokenAccount
+    )]
+    pub bounty_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = provider_token_account.owner == provider.key() @ BodyDfiError::InvalidTokenAccount,
+        constraint = provider_token_account.mint == bounty_vault.mint @ BodyDfiError::InvalidMint
+    )]
+    pub provider_token_account: Account<'info, TokenAccount>,
+    
+    /// Platform treasury token account receiving the platform fee
+    #[account(
+        mut,
+        constraint = treasury_token_account.owner == program_config.treasury @ BodyDfiError::InvalidTokenAccount,
+        constraint = treasury_token_account.mint == bounty_vault.mint @ BodyDfiError::InvalidMint
+    )]
+    pub treasury_token_account: Account<'info, TokenAccount>,
+    
+    /// Vault receiving the token holder fee
+    #[account(
+        mut,
+        address = program_config.holder_rewards_vault @ BodyDfiError::InvalidTokenAccount
+    )]
+    pub holder_rewards_vault: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Reclaim bounty account context
+#[derive(Accounts)]
+pub struct ReclaimBounty<'info> {
+    pub buyer: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"bounty", bounty.bounty_id.as_bytes()],
+        bump = bounty.bump,
+        constraint = bounty.buyer == buyer.key() @ BodyDfiError::InvalidAuthority
+    )]
+    pub bounty: Account<'info, Bounty>,
+    
+    #[account(
+        mut,
+        address = bounty.vault @ BodyDfiError::InvalidTokenAccount
+    )]
+    pub bounty_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = buyer_token_account.owner == buyer.key() @ BodyDfiError::InvalidTokenAccount,
+        constraint = buyer_token_account.mint == bounty_vault.mint @ BodyDfiError::InvalidMint
+    )]
+    pub buyer_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Create auction account context
+#[derive(Accounts)]
+#[instruction(auction_id: u64)]
+pub struct CreateAuction<'info> {
+    #[account(mut)]
+    pub provider: Signer<'info>,
+    
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_MARKETPLACE) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"data-listing", data_listing.listing_id.as_bytes()],
+        bump,
+        constraint = data_listing.provider == provider.key() @ BodyDfiError::InvalidAuthority,
+        constraint = !data_listing.is_native_sol @ BodyDfiError::NativeSolNotSupported
+    )]
+    pub data_listing: Account<'info, DataListing>,
+    
+    #[account(
+        init,
+        payer = provider,
+        seeds = [b"auction", data_listing.listing_id.as_bytes(), &auction_id.to_le_bytes()],
+        bump,
+        space = 8 + Auction::LEN
+    )]
+    pub auction: Account<'info, Auction>,
+    
+    #[account(address = data_listing.payment_mint @ BodyDfiError::InvalidMint)]
+    pub payment_mint: Account<'info, Mint>,
+    
+    /// Token account escrowing the highest English auction bid
+    #[account(
+        init,
+        payer = provider,
+        seeds = [b"auction-vault", auction.key().as_ref()],
+        bump,
+        token::mint = payment_mint,
+        token::authority = auction
+    )]
+    pub auction_vault: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Place bid account context
+#[derive(Accounts)]
+pub struct PlaceBid<'info> {
+    pub bidder: Signer<'info>,
+    
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_MARKETPLACE) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"auction", auction.listing_id.as_bytes(), &auction.auction_id.to_le_bytes()],
+        bump = auction.bump
+    )]
+    pub auction: Account<'info, Auction>,
+    
+    #[account(
+        mut,
+        address = auction.vault @ BodyDfiError::InvalidTokenAccount
+    )]
+    pub auction_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = bidder_token_account.owner == bidder.key() @ BodyDfiError::InvalidTokenAccount,
+        constraint = bidder_token_account.mint == auction.payment_mint @ BodyDfiError::InvalidMint
+    )]
+    pub bidder_token_account: Account<'info, TokenAccount>,
+    
+    /// CHECK: Bidder's access account for the listing, if any; settlement creates or extends it
+    #[account(
+        seeds = [b"data-access", bidder.key().as_ref(), auction.listing_id.as_bytes()],
+        bump
+    )]
+    pub bidder_data_access: UncheckedAccount<'info>,
+    
+    /// Token account of the outbid bidder, required once a bid exists
+    #[account(
+        mut,
+        constraint = previous_bidder_token_account.owner == auction.highest_bidder @ BodyDfiError::InvalidTokenAccount,
+        constraint = previous_bidder_token_account.mint == auction.payment_mint @ BodyDfiError::InvalidMint
+    )]
+    pub previous_bidder_token_account: Option<Account<'info, TokenAccount>>,
+    
+    pub token_program: Program<'info, Token>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Settle auction account context
+#[derive(Accounts)]
+pub struct SettleAuction<'info> {
+    /// Anyone can settle an ended auction and pays the access account rent
+    #[account(mut)]
+    pub settler: Signer<'info>,
+    
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_MARKETPLACE) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(
+        seeds = [b"marketplace-config"],
+        bump = marketplace_config.bump
+    )]
+    pub marketplace_config: Account<'info, MarketplaceConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"auction", auction.listing_id.as_bytes(), &auction.auction_id.to_le_bytes()],
+        bump = auction.bump
+    )]
+    pub auction: Account<'info, Auction>,
+    
+    #[account(
+        mut,
+        seeds = [b"data-listing", auction.listing_id.as_bytes()],
+        bump
+    )]
+    pub data_listing: Account<'info, DataListing>,
+    
+    #[account(
+        mut,
+        seeds = [b"data-provider", data_provider.user_id.as_bytes()],
+        bump,
+        constraint = data_provider.authority == auction.provider @ BodyDfiError::InvalidDataProvider
+    )]
+    pub data_provider: Account<'info, DataProvider>,
+    
+    #[account(
+        init_if_needed,
+        payer = settler,
+        seeds = [b"data-access", auction.highest_bidder.as_ref(), auction.listing_id.as_bytes()],
+        bump,
+        space = 8 + DataAccess::LEN
+    )]
+    pub data_access: Account<'info, DataAccess>,
+    
+    #[account(
+        mut,
+        address = auction.vault @ BodyDfiError::InvalidTokenAccount
+    )]
+    pub auction_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = provider_token_account.owner == auction.provider @ BodyDfiError::InvalidTokenAccount,
+        constraint = provider_token_account.mint == auction.payment_mint @ BodyDfiError::InvalidMint
+    )]
+    pub provider_token_account: Account<'info, TokenAccount>,
+    
+    /// Platform treasury token account receiving the platform fee
+    #[account(
+        mut,
+        constraint = treasury_token_account.owner == program_config.treasury @ BodyDfiError::InvalidTokenAccount,
+        constraint = treasury_token_account.mint == auction.payment_mint @ BodyDfiError::InvalidMint
+    )]
+    pub treasury_token_account: Account<'info, TokenAccount>,
+    
+    /// Vault receiving the token holder fee
+    #[account(
+        mut,
+        address = program_config.holder_rewards_vault @ BodyDfiError::InvalidTokenAccount
+    )]
+    pub holder_rewards_vault: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Buy Dutch auction account context
+#[derive(Accounts)]
+pub struct BuyDutchAuction<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_MARKETPLACE) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(
+        seeds = [b"marketplace-config"],
+        bump = marketplace_config.bump
+    )]
+    pub marketplace_config: Account<'info, MarketplaceConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"auction", auction.listing_id.as_bytes(), &auction.auction_id.to_le_bytes()],
+        bump = auction.bump
+    )]
+    pub auction: Account<'info, Auction>,
+    
+    #[account(
+        mut,
+        seeds = [b"data-listing", auction.listing_id.as_bytes()],
+        bump
+    )]
+    pub data_listing: Account<'info, DataListing>,
+    
+    #[account(
+        mut,
+        seeds = [b"data-provider", data_provider.user_id.as_bytes()],
+        bump,
+        constraint = data_provider.authority == auction.provider @ BodyDfiError::InvalidDataProvider
+    )]
+    pub data_provider: Account<'info, DataProvider>,
+    
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        seeds = [b"data-access", buyer.key().as_ref(), auction.listing_id.as_bytes()],
+        bump,
+        space = 8 + DataAccess::LEN
+    )]
+    pub data_access: Account<'info, DataAccess>,
+    
+    #[account(
+        mut,
+        constraint = buyer_token_account.mint == auction.payment_mint @ BodyDfiError::InvalidMint
+    )]
+    pub buyer_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = provider_token_account.owner == auction.provider @ BodyDfiError::InvalidTokenAccount,
+        constraint = provider_token_account.mint == auction.payment_mint @ BodyDfiError::InvalidMint
+    )]
+    pub provider_token_account: Account<'info, TokenAccount>,
+    
+    /// Platform treasury token account receiving the platform fee
+    #[account(
+        mut,
+        constraint = treasury_token_account.owner == program_config.treasury @ BodyDfiError::InvalidTokenAccount,
+        constraint = treasury_token_account.mint == auction.payment_mint @ BodyDfiError::InvalidMint
+    )]
+    pub treasury_token_account: Account<'info, TokenAccount>,
+    
+    /// Vault receiving the token holder fee
+    #[account(
+        mut,
+        address = program_config.holder_rewards_vault @ BodyDfiError::InvalidTokenAccount
+    )]
+    pub holder_rewards_vault: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Cancel auction account context
+#[derive(Accounts)]
+pub struct CancelAuction<'info> {
+    pub provider: Signer<'info>,
+    
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_MARKETPLACE) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"auction", auction.listing_id.as_bytes(), &auction.auction_id.to_le_bytes()],
+        bump = auction.bump,
+        constraint = auction.provider == provider.key() @ BodyDfiError::InvalidAuthority
+    )]
+    pub auction: Account<'info, Auction>,
+    
+    #[account(
+        mut,
+        seeds = [b"data-listing", auction.listing_id.as_bytes()],
+        bump
+    )]
+    pub data_listing: Account<'info, DataListing>,
+    
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Refund auction bid account context
+#[derive(Accounts)]
+pub struct RefundAuctionBid<'info> {
+    /// Anyone can trigger the refund once the settlement window has passed
+    pub caller: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"auction", auction.listing_id.as_bytes(), &auction.auction_id.to_le_bytes()],
+        bump = auction.bump
+    )]
+    pub auction: Account<'info, Auction>,
+    
+    #[account(
+        mut,
+        seeds = [b"data-listing", auction.listing_id.as_bytes()],
+        bump
+    )]
+    pub data_listing: Account<'info, DataListing>,
+    
+    #[account(
+        mut,
+        address = auction.vault @ BodyDfiError::InvalidTokenAccount
+    )]
+    pub auction_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = bidder_token_account.owner == auction.highest_bidder @ BodyDfiError::InvalidTokenAccount,
+        constraint = bidder_token_account.mint == auction.payment_mint @ BodyDfiError::InvalidMint
+    )]
+    pub bidder_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Create data pool account context
+#[derive(Accounts)]
+#[instruction(pool_id: String)]
+pub struct CreateDataPool<'info> {
+    #[account(mut)]
+    pub curator: Signer<'info>,
+    
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_MARKETPLACE) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(
+        init,
+        payer = curator,
+        seeds = [b"data-pool", pool_id.as_bytes()],
+        bump,
+        space = 8 + DataPool::LEN
+    )]
+    pub data_pool: Account<'info, DataPool>,
+    
+    /// Pooled listing buyers purchase access to, owned by the pool
+    #[account(
+        init,
+        payer = curator,
+        seeds = [b"data-listing", pool_id.as_bytes()],
+        bump,
+        space = 8 + DataListing::LEN
+    )]
+    pub data_listing: Account<'info, DataListing>,
+    
+    pub payment_mint: Account<'info, Mint>,
+    
+    #[account(
+        seeds = [b"payment-mint", payment_mint.key().as_ref()],
+        bump = allowed_payment_mint.bump,
+        constraint = allowed_payment_mint.is_allowed @ BodyDfiError::InvalidPaymentMint
+    )]
+    pub allowed_payment_mint: Account<'info, AllowedPaymentMint>,
+    
+    /// Token account collecting the members' share of pool revenue
+    #[account(
+        init,
+        payer = curator,
+        seeds = [b"pool-vault", data_pool.key().as_ref()],
+        bump,
+        token::mint = payment_mint,
+        token::authority = data_pool
+    )]
+    pub revenue_vault: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Join data pool account context
+#[derive(Accounts)]
+pub struct JoinDataPool<'info> {
+    #[account(mut)]
+    pub provider: Signer<'info>,
+    
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_MARKETPLACE) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(
+        seeds = [b"data-pool", data_pool.pool_id.as_bytes()],
+        bump = data_pool.bump
+    )]
+    pub data_pool: Account<'info, DataPool>,
+    
+    #[account(
+        seeds = [b"data-provider", data_provider.user_id.as_bytes()],
+        bump,
+        constraint = data_provider.authority == provider.key() @ BodyDfiError::InvalidAuthority
+    )]
+    pub data_provider: Account<'info, DataProvider>,
+    
+    #[account(
+        init,
+        payer = provider,
+        seeds = [b"pool-member", data_pool.key().as_ref(), provider.key().as_ref()],
+        bump,
+        space = 8 + PoolMembership::LEN
+    )]
+    pub pool_membership: Account<'info, PoolMembership>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Commit submission to pool account context
+#[derive(Accounts)]
+pub struct CommitPoolSubmission<'info> {
+    #[account(mut)]
+    pub provider: Signer<'info>,
+    
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_MARKETPLACE) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"data-pool", data_pool.pool_id.as_bytes()],
+        bump = data_pool.bump
+    )]
+    pub data_pool: Account<'info, DataPool>,
+    
+    #[account(
+        mut,
+        seeds = [b"pool-member", data_pool.key().as_ref(), provider.key().as_ref()],
+        bump = pool_membership.bump
+    )]
+    pub pool_membership: Account<'info, PoolMembership>,
+    
+    #[account(
+        seeds = [b"data-submission", data_submission.data_hash.as_bytes()],
+        bump,
+        constraint = data_submission.provider == provider.key() @ BodyDfiError::InvalidAuthority
+    )]
+    pub data_submission: Account<'info, DataSubmission>,
+    
+    /// Marks a submission as committed so it counts towards one pool only
+    #[account(
+        init,
+        payer = provider,
+        seeds = [b"pool-commitment", data_submission.key().as_ref()],
+        bump,
+        space = 8 + PoolCommitment::LEN
+    )]
+    pub pool_commitment: Account<'info, PoolCommitment>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Purchase pool access account context
+#[derive(Accounts)]
+pub struct PurchasePoolAccess<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_MARKETPLACE) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(
+        seeds = [b"marketplace-config"],
+        bump = marketplace_config.bump
+    )]
+    pub marketplace_config: Account<'info, MarketplaceConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"data-pool", data_pool.pool_id.as_bytes()],
+        bump = data_pool.bump
+    )]
+    pub data_pool: Account<'info, DataPool>,
+    
+    #[account(
+        mut,
+        seeds = [b"data-listing", data_pool.pool_id.as_bytes()],
+        bump,
+        constraint = data_listing.provider == data_pool.key() @ BodyDfiError::InvalidDataListing
+    )]
+    pub data_listing: Account<'info, DataListing>,
+    
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        seeds = [b"data-access", buyer.key().as_ref(), data_pool.pool_id.as_bytes()],
+        bump,
+        space = 8 + DataAccess::LEN
+    )]
+    pub data_access: Account<'info, DataAccess>,
+    
+    #[account(
+        mut,
+        constraint = buyer_token_account.mint == data_listing.payment_mint @ BodyDfiError::InvalidMint
+    )]
+    pub buyer_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        address = data_pool.revenue_vault @ BodyDfiError::InvalidTokenAccount
+    )]
+    pub revenue_vault: Account<'info, TokenAccount>,
+    
+    /// Platform treasury token account receiving the platform fee
+    #[account(
+        mut,
+        constraint = treasury_token_account.owner == program_config.treasury @ BodyDfiError::InvalidTokenAccount,
+        constraint = treasury_token_account.mint == data_listing.payment_mint @ BodyDfiError::InvalidMint
+    )]
+    pub treasury_token_account: Account<'info, TokenAccount>,
+    
+    /// Vault receiving the token holder fee
+    #[account(
+        mut,
+        address = program_config.holder_rewards_vault @ BodyDfiError::InvalidTokenAccount
+    )]
+    pub holder_rewards_vault: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Claim pool revenue account context
+#[derive(Accounts)]
+pub struct ClaimPoolRevenue<'info> {
+    pub provider: Signer<'info>,
+    
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_MARKETPLACE) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"data-pool", data_pool.pool_id.as_bytes()],
+        bump = data_pool.bump
+    )]
+    pub data_pool: Account<'info, DataPool>,
+    
+    #[account(
+        mut,
+        seeds = [b"pool-member", data_pool.key().as_ref(), provider.key().as_ref()],
+        bump = pool_membership.bump
+    )]
+    pub pool_membership: Account<'info, PoolMembership>,
+    
+    #[account(
+        mut,
+        address = data_pool.revenue_vault @ BodyDfiError::InvalidTokenAccount
+    )]
+    pub revenue_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = provider_token_account.owner == provider.key() @ BodyDfiError::InvalidTokenAccount,
+        constraint = provider_token_account.mint == revenue_vault.mint @ BodyDfiError::InvalidMint
+    )]
+    pub provider_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+/// Manage pooled listing account context
+#[derive(Accounts)]
+pub struct ManagePoolListing<'info> {
+    pub curator: Signer<'info>,
+    
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_MARKETPLACE) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"data-pool", data_pool.pool_id.as_bytes()],
+        bump = data_pool.bump,
+        constraint = data_pool.curator == curator.key() @ BodyDfiError::InvalidAuthority
+    )]
+    pub data_pool: Account<'info, DataPool>,
+    
+    #[account(
+        mut,
+        seeds = [b"data-listing", data_pool.pool_id.as_bytes()],
+        bump,
+        constraint = data_listing.provider == data_pool.key() @ BodyDfiError::InvalidDataListing,
+        constraint = !data_listing.is_closed @ BodyDfiError::ListingClosed
+    )]
+    pub data_listing: Account<'info, DataListing>,
+}
+
+/// Close pooled listing account context
+#[derive(Accounts)]
+pub struct ClosePoolListing<'info> {
+    #[account(mut)]
+    pub curator: Signer<'info>,
+    
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_MARKETPLACE) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(
+        seeds = [b"data-pool", data_pool.pool_id.as_bytes()],
+        bump = data_pool.bump,
+        constraint = data_pool.curator == curator.key() @ BodyDfiError::InvalidAuthority
+    )]
+    pub data_pool: Account<'info, DataPool>,
+    
+    #[account(
+        mut,
+        seeds = [b"data-listing", data_pool.pool_id.as_bytes()],
+        bump,
+        constraint = data_listing.provider == data_pool.key() @ BodyDfiError::InvalidDataListing,
+        constraint = !data_listing.is_closed @ BodyDfiError::ListingClosed,
+        realloc = 8 + data_listing.tombstone_len(),
+        realloc::payer = curator,
+        realloc::zero = false
+    )]
+    pub data_listing: Account<'info, DataListing>,
+    
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Revoke pooled access account context
+#[derive(Accounts)]
+pub struct RevokePoolAccess<'info> {
+    pub curator: Signer<'info>,
+    
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_MARKETPLACE) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(
+        seeds = [b"data-pool", data_pool.pool_id.as_bytes()],
+        bump = data_pool.bump,
+        constraint = data_pool.curator == curator.key() @ BodyDfiError::InvalidAuthority
+    )]
+    pub data_pool: Account<'info, DataPool>,
+    
+    #[account(
+        seeds = [b"data-listing", data_pool.pool_id.as_bytes()],
+        bump,
+        constraint = data_listing.provider == data_pool.key() @ BodyDfiError::InvalidDataListing
+    )]
+    pub data_listing: Account<'info, DataListing>,
+    
+    #[account(
+        mut,
+        seeds = [b"data-access", data_access.buyer.as_ref(), data_pool.pool_id.as_bytes()],
+        bump
+    )]
+    pub data_access: Account<'info, DataAccess>,
+    
+    #[account(
+        mut,
+        constraint = buyer_token_account.owner == data_access.buyer @ BodyDfiError::InvalidTokenAccount,
+        constraint = buyer_token_account.mint == data_listing.payment_mint @ BodyDfiError::InvalidMint
+    )]
+    pub buyer_token_account: Account<'info, TokenAccount>,
+    
+    /// Curator token account funding the refund
+    #[account(
+        mut,
+        constraint = curator_token_account.owner == curator.key() @ BodyDfiError::InvalidTokenAccount,
+        constraint = curator_token_account.mint == data_listing.payment_mint @ BodyDfiError::InvalidMint
+    )]
+    pub curator_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Purchase license account context
+#[derive(Accounts)]
+pub struct PurchaseLicense<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_MARKETPLACE) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(
+        seeds = [b"marketplace-config"],
+        bump = marketplace_config.bump
+    )]
+    pub marketplace_config: Account<'info, MarketplaceConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"data-listing", data_listing.listing_id.as_bytes()],
+        bump,
+        constraint = !data_listing.is_native_sol @ BodyDfiError::NativeSolNotSupported
+    )]
+    pub data_listing: Account<'info, DataListing>,
+    
+    #[account(
+        mut,
+        seeds = [b"data-provider", data_provider.user_id.as_bytes()],
+        bump,
+        constraint = data_provider.authority == data_listing.provider @ BodyDfiError::InvalidDataProvider
+    )]
+    pub data_provider: Account<'info, DataProvider>,
+    
+    /// License record indexed by the listing's purchase count
+    #[account(
+        init,
+        payer = buyer,
+        seeds = [b"license", data_listing.listing_id.as_bytes(), &data_listing.purchase_count.to_le_bytes()],
+        bump,
+        space = 8 + LicenseRecord::LEN
+    )]
+    pub license_record: Account<'info, LicenseRecord>,
+    
+    #[account(
+        init,
+        payer = buyer,
+        seeds = [b"license-mint", license_record.key().as_ref()],
+        bump,
+        mint::decimals = 0,
+        mint::authority = license_record,
+        mint::freeze_authority = license_record
+    )]
+    pub license_mint: Account<'info, Mint>,
+    
+    #[account(
+        init,
+        payer = buyer,
+        associated_token::mint = license_mint,
+        associated_token::authority = buyer
+    )]
+    pub buyer_license_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = buyer_token_account.mint == data_listing.payment_mint @ BodyDfiError::InvalidMint
+    )]
+    pub buyer_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = provider_token_account.owner == data_listing.provider @ BodyDfiError::InvalidTokenAccount,
+        constraint = provider_token_account.mint == data_listing.payment_mint @ BodyDfiError::InvalidMint
+    )]
+    pub provider_token_account: Account<'info, TokenAccount>,
+    
+    /// Platform treasury token account receiving the platform fee
+    #[account(
+        mut,
+        constraint = treasury_token_account.owner == program_config.treasury @ BodyDfiError::InvalidTokenAccount,
+        constraint = treasury_token_account.mint == data_listing.payment_mint @ BodyDfiError::InvalidMint
+    )]
+    pub treasury_token_account: Account<'info, TokenAccount>,
+    
+    /// Vault receiving the token holder fee
+    #[account(
+        mut,
+        address = program_config.holder_rewards_vault @ BodyDfiError::InvalidTokenAccount
+    )]
+    pub holder_rewards_vault: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Resell license account context
+#[derive(Accounts)]
+pub struct ResellLicense<'info> {
+    pub seller: Signer<'info>,
+    
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_MARKETPLACE) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(
+        seeds = [b"data-listing", license_record.listing_id.as_bytes()],
+        bump
+    )]
+    pub data_listing: Account<'info, DataListing>,
+    
+    #[account(
+        seeds = [b"license", license_record.listing_id.as_bytes(), &license_record.license_index.to_le_bytes()],
+        bump = license_record.bump
+    )]
+    pub license_record: Account<'info, LicenseRecord>,
+    
+    #[account(address = license_record.license_mint @ BodyDfiError::InvalidMint)]
+    pub license_mint: Account<'info, Mint>,
+    
+    #[account(
+        mut,
+        constraint = seller_license_account.owner == seller.key() @ BodyDfiError::InvalidTokenAccount,
+        constraint = seller_license_account.mint == license_mint.key() @ BodyDfiError::InvalidMint
+    )]
+    pub seller_license_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        associated_token::mint = license_mint,
+        associated_token::authority = buyer
+    )]
+    pub buyer_license_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = buyer_token_account.mint == data_listing.payment_mint @ BodyDfiError::InvalidMint
+    )]
+    pub buyer_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = seller_token_account.owner == seller.key() @ BodyDfiError::InvalidTokenAccount,
+        constraint = seller_token_account.mint == data_listing.payment_mint @ BodyDfiError::InvalidMint
+    )]
+    pub seller_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = provider_token_account.owner == data_listing.provider @ BodyDfiError::InvalidTokenAccount,
+        constraint = provider_token_account.mint == data_listing.payment_mint @ BodyDfiError::InvalidMint
+    )]
+    pub provider_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Verify data access account context
+#[derive(Accounts)]
+pub struct VerifyDataAccess<'info> {
+    #[account(
+        seeds = [b"data-access", data_access.buyer.as_ref(), data_access.listing_id.as_bytes()],
+        bump
+    )]
+    pub data_access: Account<'info, DataAccess>,
+    
+    #[account(
+        seeds = [b"data-listing", data_access.listing_id.as_bytes()],
+        bump
+    )]
+    pub data_listing: Account<'info, DataListing>,
+    
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Verify license access account context
+#[derive(Accounts)]
+pub struct VerifyLicenseAccess<'info> {
+    #[account(
+        seeds = [b"license", license_record.listing_id.as_bytes(), &license_record.license_index.to_le_bytes()],
+        bump = license_record.bump
+    )]
+    pub license_record: Account<'info, LicenseRecord>,
+    
+    #[account(
+        seeds = [b"data-listing", license_record.listing_id.as_bytes()],
+        bump
+    )]
+    pub data_listing: Account<'info, DataListing>,
+    
+    /// Token account holding the license; its owner is the verified holder
+    pub holder_license_account: Account<'info, TokenAccount>,
+    
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Revoke data access account context
+#[derive(Accounts)]
+pub struct RevokeDataAccess<'info> {
+    #[account(mut)]
+    pub provider: Signer<'info>,
+    
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_MARKETPLACE) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(
+        seeds = [b"data-listing", data_access.listing_id.as_bytes()],
+        bump,
+        constraint = data_listing.provider == provider.key() @ BodyDfiError::InvalidAuthority
+    )]
+    pub data_listing: Account<'info, DataListing>,
+    
+    #[account(
+        mut,
+        seeds = [b"data-access", data_access.buyer.as_ref(), data_access.listing_id.as_bytes()],
+        bump
+    )]
+    pub data_access: Account<'info, DataAccess>,
+    
+    #[account(
+        mut,
+        constraint = buyer_token_account.owner == data_access.buyer @ BodyDfiError::InvalidTokenAccount,
+        constraint = buyer_token_account.mint == data_listing.payment_mint @ BodyDfiError::InvalidMint
+    )]
+    pub buyer_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// CHECK: Buyer wallet receiving SOL refunds
+    #[account(
+        mut,
+        address = data_access.buyer @ BodyDfiError::InvalidAuthority
+    )]
+    pub buyer_wallet: Option<UncheckedAccount<'info>>,
+    
+    /// Provider token account funding the refund beyond what the escrow holds
+    #[account(
+        mut,
+        constraint = provider_token_account.owner == provider.key() @ BodyDfiError::InvalidTokenAccount,
+        constraint = provider_token_account.mint == data_listing.payment_mint @ BodyDfiError::InvalidMint
+    )]
+    pub provider_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Escrow still holding the payment, funding the refund when present
+    #[account(
+        mut,
+        seeds = [b"purchase-escrow", data_access.key().as_ref()],
+        bump = purchase_escrow.bump
+    )]
+    pub purchase_escrow: Option<Account<'info, PurchaseEscrow>>,
+    
+    #[account(mut)]
+    pub escrow_vault: Option<Account<'info, TokenAccount>>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Revoke license account context
+#[derive(Accounts)]
+pub struct RevokeLicense<'info> {
+    pub provider: Signer<'info>,
+    
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_MARKETPLACE) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(
+        seeds = [b"data-listing", license_record.listing_id.as_bytes()],
+        bump,
+        constraint = data_listing.provider == provider.key() @ BodyDfiError::InvalidAuthority
+    )]
+    pub data_listing: Account<'info, DataListing>,
+    
+    #[account(
+        mut,
+        seeds = [b"license", license_record.listing_id.as_bytes(), &license_record.license_index.to_le_bytes()],
+        bump = license_record.bump
+    )]
+    pub license_record: Account<'info, LicenseRecord>,
+    
+    /// Token account currently holding the license; its owner receives the refund
+    #[account(
+        constraint = holder_license_account.mint == license_record.license_mint @ BodyDfiError::InvalidLicense,
+        constraint = holder_license_account.amount == 1 @ BodyDfiError::InvalidLicense
+    )]
+    pub holder_license_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = holder_token_account.owner == holder_license_account.owner @ BodyDfiError::InvalidTokenAccount,
+        constraint = holder_token_account.mint == data_listing.payment_mint @ BodyDfiError::InvalidMint
+    )]
+    pub holder_token_account: Account<'info, TokenAccount>,
+    
+    /// Provider token account funding the refund
+    #[account(
+        mut,
+        constraint = provider_token_account.owner == provider.key() @ BodyDfiError::InvalidTokenAccount,
+        constraint = provider_token_account.mint == data_listing.payment_mint @ BodyDfiError::InvalidMint
+    )]
+    pub provider_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Initialize marketplace config account context
+#[derive(Accounts)]
+pub struct InitializeMarketplaceConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = program_config.admin == admin.key() @ BodyDfiError::InvalidAuthority
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"marketplace-config"],
+        bump,
+        space = 8 + MarketplaceConfig::LEN
+    )]
+    pub marketplace_config: Account<'info, MarketplaceConfig>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Execute fee parameter change account context
+#[derive(Accounts)]
+pub struct ExecuteFeeParameterChange<'info> {
+    pub executor: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"proposal", &proposal.id.to_le_bytes()],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+    
+    #[account(
+        seeds = [b"parameter-change".as_ref(), &proposal.id.to_le_bytes()],
+        bump
+    )]
+    pub parameter_change: Account<'info, ParameterChange>,
+    
+    #[account(
+        mut,
+        seeds = [b"marketplace-config"],
+        bump = marketplace_config.bump
+    )]
+    pub marketplace_config: Account<'info, MarketplaceConfig>,
+}
+
+/// Execute payment mint parameter change account context
+#[derive(Accounts)]
+pub struct ExecutePaymentMintChange<'info> {
+    #[account(mut)]
+    pub executor: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"proposal", &proposal.id.to_le_bytes()],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+    
+    #[account(
+        seeds = [b"parameter-change".as_ref(), &proposal.id.to_le_bytes()],
+        bump
+    )]
+    pub parameter_change: Account<'info, ParameterChange>,
+    
+    #[account(address = parameter_change.target @ BodyDfiError::InvalidMint)]
+    pub payment_mint: Account<'info, Mint>,
+    
+    #[account(
+        init_if_needed,
+        payer = executor,
+        seeds = [b"payment-mint", payment_mint.key().as_ref()],
+        bump,
+        space = 8 + AllowedPaymentMint::LEN
+    )]
+    pub allowed_payment_mint: Account<'info, AllowedPaymentMint>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Update escrow settings account context
+#[derive(Accounts)]
+pub struct UpdateEscrowSettings<'info> {
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = program_config.admin == admin.key() @ BodyDfiError::InvalidAuthority
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"marketplace-config"],
+        bump = marketplace_config.bump
+    )]
+    pub marketplace_config: Account<'info, MarketplaceConfig>,
+}
+
+/// Escrowed purchase account context
+#[derive(Accounts)]
+pub struct PurchaseDataAccessEscrowed<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_MARKETPLACE) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(
+        seeds = [b"marketplace-config"],
+        bump = marketplace_config.bump
+    )]
+    pub marketplace_config: Account<'info, MarketplaceConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"data-listing", data_listing.listing_id.as_bytes()],
+        bump,
+        constraint = !data_listing.is_native_sol @ BodyDfiError::NativeSolNotSupported
+    )]
+    pub data_listing: Account<'info, DataListing>,
+    
+    #[account(
+        init,
+        payer = buyer,
+        seeds = [b"data-access", buyer.key().as_ref(), data_listing.listing_id.as_bytes()],
+        bump,
+        space = 8 + DataAccess::LEN
+    )]
+    pub data_access: Account<'info, DataAccess>,
+    
+    #[account(
+        init,
+        payer = buyer,
+        seeds = [b"purchase-escrow", data_access.key().as_ref()],
+        bump,
+        space = 8 + PurchaseEscrow::LEN
+    )]
+    pub purchase_escrow: Account<'info, PurchaseEscrow>,
+    
+    #[account(
+        mut,
+        constraint = buyer_token_account.mint == data_listing.payment_mint @ BodyDfiError::InvalidMint
+    )]
+    pub buyer_token_account: Account<'info, TokenAccount>,
+    
+    #[account(address = data_listing.payment_mint @ BodyDfiError::InvalidMint)]
+    pub payment_mint: Account<'info, Mint>,
+    
+    /// Token account holding the payment until release
+    #[account(
+        init,
+        payer = buyer,
+        seeds = [b"escrow-vault", purchase_escrow.key().as_ref()],
+        bump,
+        token::mint = payment_mint,
+        token::authority = purchase_escrow
+    )]
+    pub escrow_vault: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Escrow buyer action account context
+#[derive(Accounts)]
+pub struct EscrowBuyerAction<'info> {
+    pub buyer: Signer<'info>,
+    
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_MARKETPLACE) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"purchase-escrow", purchase_escrow.data_access.as_ref()],
+        bump = purchase_escrow.bump,
+        constraint = purchase_escrow.buyer == buyer.key() @ BodyDfiError::InvalidAuthority
+    )]
+    pub purchase_escrow: Account<'info, PurchaseEscrow>,
+    
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Release escrow account context
+#[derive(Accounts)]
+pub struct ReleaseEscrow<'info> {
+    pub provider: Signer<'info>,
+    
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_MARKETPLACE) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(
+        seeds = [b"marketplace-config"],
+        bump = marketplace_config.bump
+    )]
+    pub marketplace_config: Account<'info, MarketplaceConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"purchase-escrow", purchase_escrow.data_access.as_ref()],
+        bump = purchase_escrow.bump,
+        constraint = purchase_escrow.provider == provider.key() @ BodyDfiError::InvalidAuthority
+    )]
+    pub purchase_escrow: Account<'info, PurchaseEscrow>,
+    
+    #[account(
+        mut,
+        seeds = [b"data-provider", data_provider.user_id.as_bytes()],
+        bump,
+        constraint = data_provider.authority == purchase_escrow.provider @ BodyDfiError::InvalidDataProvider
+    )]
+    pub data_provider: Account<'info, DataProvider>,
+    
+    #[account(
+        mut,
+        address = purchase_escrow.vault @ BodyDfiError::InvalidTokenAccount
+    )]
+    pub escrow_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = provider_token_account.owner == purchase_escrow.provider @ BodyDfiError::InvalidTokenAccount,
+        constraint = provider_token_account.mint == escrow_vault.mint @ BodyDfiError::InvalidMint
+    )]
+    pub provider_token_account: Account<'info, TokenAccount>,
+    
+    /// Platform treasury token account receiving the platform fee
+    #[account(
+        mut,
+        constraint = treasury_token_account.owner == program_config.treasury @ BodyDfiError::InvalidTokenAccount,
+        constraint = treasury_token_account.mint == escrow_vault.mint @ BodyDfiError::InvalidMint
+    )]
+    pub treasury_token_account: Account<'info, TokenAccount>,
     
+    /// Vault receiving the token holder fee
     #[account(
-        init,
-        payer = user,
-        seeds = [b"data-listing", listing_id.as_bytes()],
-        bump,
-        space = 8 + DataListing::LEN
+        mut,
+        address = program_config.holder_rewards_vault @ BodyDfiError::InvalidTokenAccount
     )]
-    pub data_listing: Account<'info, DataListing>,
+    pub holder_rewards_vault: Account<'info, TokenAccount>,
     
-    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
     pub clock: Sysvar<'info, Clock>,
 }
 
-/// Purchase data access account context
+/// Resolve dispute account context
 #[derive(Accounts)]
-pub struct PurchaseDataAccess<'info> {
-    #[account(mut)]
-    pub buyer: Signer<'info>,
+pub struct ResolveDispute<'info> {
+    pub arbiter: Signer<'info>,
+    
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(
+        seeds = [b"marketplace-config"],
+        bump = marketplace_config.bump,
+        constraint = marketplace_config.arbiter == arbiter.key() @ BodyDfiError::InvalidAuthority
+    )]
+    pub marketplace_config: Account<'info, MarketplaceConfig>,
     
     #[account(
         mut,
-        seeds = [b"data-listing", data_listing.listing_id.as_bytes()],
-        bump
+        seeds = [b"purchase-escrow", purchase_escrow.data_access.as_ref()],
+        bump = purchase_escrow.bump
     )]
-    pub data_listing: Account<'info, DataListing>,
+    pub purchase_escrow: Account<'info, PurchaseEscrow>,
+    
+    #[account(
+        mut,
+        address = purchase_escrow.data_access @ BodyDfiError::InvalidDataListing
+    )]
+    pub data_access: Account<'info, DataAccess>,
     
     #[account(
         mut,
         seeds = [b"data-provider", data_provider.user_id.as_bytes()],
-        bump
+        bump,
+        constraint = data_provider.authority == purchase_escrow.provider @ BodyDfiError::InvalidDataProvider
     )]
     pub data_provider: Account<'info, DataProvider>,
     
     #[account(
-        init,
-        payer = buyer,
-        seeds = [b"data-access", buyer.key().as_ref(), data_listing.listing_id.as_bytes()],
-        bump,
-        space = 8 + DataAccess::LEN
+        mut,
+        address = purchase_escrow.vault @ BodyDfiError::InvalidTokenAccount
     )]
-    pub data_access: Account<'info, DataAccess>,
+    pub escrow_vault: Account<'info, TokenAccount>,
     
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = buyer_token_account.owner == purchase_escrow.buyer @ BodyDfiError::InvalidTokenAccount,
+        constraint = buyer_token_account.mint == escrow_vault.mint @ BodyDfiError::InvalidMint
+    )]
     pub buyer_token_account: Account<'info, TokenAccount>,
     
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = provider_token_account.owner == purchase_escrow.provider @ BodyDfiError::InvalidTokenAccount,
+        constraint = provider_token_account.mint == escrow_vault.mint @ BodyDfiError::InvalidMint
+    )]
     pub provider_token_account: Account<'info, TokenAccount>,
     
-    pub token_program: Program<'info, anchor_spl::token::Token>,
-    pub system_program: Program<'info, System>,
+    /// Platform treasury token account receiving the platform fee
+    #[account(
+        mut,
+        constraint = treasury_token_account.owner == program_config.treasury @ BodyDfiError::InvalidTokenAccount,
+        constraint = treasury_token_account.mint == escrow_vault.mint @ BodyDfiError::InvalidMint
+    )]
+    pub treasury_token_account: Account<'info, TokenAccount>,
+    
+    /// Vault receiving the token holder fee
+    #[account(
+        mut,
+        address = program_config.holder_rewards_vault @ BodyDfiError::InvalidTokenAccount
+    )]
+    pub holder_rewards_vault: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
     pub clock: Sysvar<'info, Clock>,
 }
 
+/// Marketplace configuration account
+#[account]
+pub struct MarketplaceConfig {
+    /// Platform share of each payment in basis points
+    pub platform_fee_bps: u16,
+    
+    /// Token holder share of each payment in basis points
+    pub token_holder_fee_bps: u16,
+    
+    /// Provider share of each payment in basis points
+    pub provider_fee_bps: u16,
+    
+    /// Arbiter resolving escrow disputes
+    pub arbiter: Pubkey,
+    
+    /// Time after an escrowed purchase during which the buyer can dispute
+    pub dispute_window: i64,
+    
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl MarketplaceConfig {
+    pub const LEN: usize = 2 + 2 + 2 + 32 + 8 + 1;
+}
+
 /// Data provider account
 #[account]
 pub struct DataProvider {
@@ -151,10 +2142,13 @@ pub struct DataProvider {
     
     /// Reputation score (calculated from quality and consistency)
     pub reputation_score: u16,
+    
+    /// Last reward timestamp, used for the per-provider reward cooldown
+    pub last_reward_timestamp: i64,
 }
 
 impl DataProvider {
-    pub const LEN: usize = 32 + 64 + 1 + 8 + 8 + 8 + 1 + 2;
+    pub const LEN: usize = 32 + 64 + 1 + 8 + 8 + 8 + 1 + 2 + 8;
 }
 
 /// Data submission account
@@ -180,10 +2174,13 @@ pub struct DataSubmission {
     
     /// Whether this data has been validated
     pub is_validated: bool,
+    
+    /// Whether a reward has been paid for this data
+    pub is_rewarded: bool,
 }
 
 impl DataSubmission {
-    pub const LEN: usize = 32 + 64 + 1 + 8 + 128 + 1 + 1;
+    pub const LEN: usize = 32 + 64 + 1 + 8 + 128 + 1 + 1 + 1;
 }
 
 /// Data listing account
@@ -215,10 +2212,56 @@ pub struct DataListing {
     
     /// Whether this listing is active
     pub is_active: bool,
+    
+    /// Latest expiry of any access granted on this listing
+    pub latest_access_expiry: i64,
+    
+    /// Whether purchases are issued as transferable license tokens
+    pub transferable_license: bool,
+    
+    /// Provider royalty on license resales in basis points
+    pub royalty_bps: u16,
+    
+    /// Fixed-price sales are closed until this timestamp while an auction runs or its winner holds exclusive access
+    pub exclusive_until: i64,
+    
+    /// SPL mint accepted as payment; unused for SOL-priced listings
+    pub payment_mint: Pubkey,
+    
+    /// Whether the listing is priced in native SOL
+    pub is_native_sol: bool,
+    
+    /// Incremented whenever the provider changes the listing's terms
+    pub listing_version: u64,
+    
+    /// Closed listings stay on chain so their ID cannot be reused
+    pub is_closed: bool,
 }
 
 impl DataListing {
-    pub const LEN: usize = 32 + 64 + 32 + 8 + 8 + 256 + 8 + 8 + 1;
+    pub const LEN: usize = 32 + 64 + 32 + 8 + 8 + 256 + 8 + 8 + 1 + 8 + 1 + 2 + 8 + 32 + 1 + 8 + 1;
+    
+    /// Longest listing ID, in bytes, that fits the account
+    pub const MAX_LISTING_ID_LEN: usize = 64 - 4;
+    
+    /// Most data types that fit the account
+    pub const MAX_DATA_TYPES: usize = 32 - 4;
+    
+    /// Longest description, in bytes, that fits the account
+    pub const MAX_DESCRIPTION_LEN: usize = 256 - 4;
+    
+    /// Whether the listing can be sold at a given timestamp
+    pub fn is_open_for_sale(&self, timestamp: i64) -> bool {
+        self.is_active && timestamp >= self.exclusive_until
+    }
+    
+    /// Size of the listing once closed: its ID is kept, data types and description are cleared
+    pub fn tombstone_len(&self) -> usize {
+        Self::LEN
+            - (Self::MAX_LISTING_ID_LEN - self.listing_id.len().min(Self::MAX_LISTING_ID_LEN))
+            - Self::MAX_DATA_TYPES
+            - Self::MAX_DESCRIPTION_LEN
+    }
 }
 
 /// Data access account
@@ -236,16 +2279,505 @@ pub struct DataAccess {
     /// Timestamp when access expires
     pub expires_at: i64,
     
-    /// Amount paid for access
+    /// Cumulative amount paid for access
     pub amount_paid: u64,
     
     /// Whether access is still valid
     pub is_valid: bool,
+    
+    /// Number of times access was renewed
+    pub renewal_count: u64,
 }
 
 impl DataAccess {
-    pub const LEN: usize = 32 + 64 + 8 + 8 + 8 + 1;
+    pub const LEN: usize = 32 + 64 + 8 + 8 + 8 + 1 + 8;
+}
+
+/// Result of a successful data access check
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AccessInfo {
+    /// Buyer holding access
+    pub buyer: Pubkey,
+    
+    /// Provider of the listing
+    pub provider: Pubkey,
+    
+    /// Data listing accessed
+    pub listing_id: String,
+    
+    /// Data types covered by the listing
+    pub data_types: Vec<u8>,
+    
+    /// Timestamp when access expires
+    pub expires_at: i64,
+    
+    /// Seconds of access remaining
+    pub remaining_seconds: i64,
+}
+
+/// Governance-maintained allowlist entry for a payment mint
+#[account]
+pub struct AllowedPaymentMint {
+    /// SPL mint listings can be priced in
+    pub mint: Pubkey,
+    
+    /// Whether new listings can use this mint
+    pub is_allowed: bool,
+    
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl AllowedPaymentMint {
+    pub const LEN: usize = 32 + 1 + 1;
+}
+
+/// Escrow status enum
+pub enum EscrowStatus {
+    Held = 0,
+    Disputed = 1,
+    Released = 2,
+    Resolved = 3,
+}
+
+/// Escrow holding an escrowed purchase payment
+#[account]
+pub struct PurchaseEscrow {
+    /// Data access granted by the purchase
+    pub data_access: Pubkey,
+    
+    /// Buyer who paid
+    pub buyer: Pubkey,
+    
+    /// Provider being paid
+    pub provider: Pubkey,
+    
+    /// Token account holding the payment
+    pub vault: Pubkey,
+    
+    /// Amount held in escrow
+    pub amount: u64,
+    
+    /// Timestamp after which the provider can release without confirmation
+    pub release_after: i64,
+    
+    /// Whether the buyer confirmed delivery
+    pub delivery_confirmed: bool,
+    
+    /// Status of the escrow
+    pub status: u8,
+    
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl PurchaseEscrow {
+    pub const LEN: usize = 32 + 32 + 32 + 32 + 8 + 8 + 1 + 1 + 1;
+}
+
+/// Recurring subscription billing a data listing each period
+#[account]
+pub struct Subscription {
+    /// Buyer paying for the subscription
+    pub buyer: Pubkey,
+    
+    /// Data access extended by each charge
+    pub data_access: Pubkey,
+    
+    /// Data listing subscribed to
+    pub listing_id: String,
+    
+    /// Buyer token account the subscription is delegated on
+    pub buyer_token_account: Pubkey,
+    
+    /// Billing period in seconds
+    pub billing_period: u64,
+    
+    /// Maximum price the buyer accepts per period
+    pub max_price: u64,
+    
+    /// Timestamp from which the next charge can be made
+    pub next_charge_at: i64,
+    
+    /// Number of successful charges
+    pub charge_count: u64,
+    
+    /// Whether the subscription is still active
+    pub is_active: bool,
+    
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl Subscription {
+    pub const LEN: usize = 32 + 32 + 64 + 32 + 8 + 8 + 8 + 8 + 1 + 1;
+}
+
+/// Buyer bounty paying providers for access to requested data
+#[account]
+pub struct Bounty {
+    /// Buyer who posted the bounty
+    pub buyer: Pubkey,
+    
+    /// Unique bounty ID
+    pub bounty_id: String,
+    
+    /// Data types requested
+    pub data_types: Vec<u8>,
+    
+    /// Device types accepted
+    pub device_types: Vec<u8>,
+    
+    /// Minimum provider reputation score
+    pub min_reputation_score: u16,
+    
+    /// Amount paid to each accepting provider
+    pub price_per_provider: u64,
+    
+    /// Maximum number of providers that can accept
+    pub max_providers: u64,
+    
+    /// Number of providers that accepted
+    pub accepted_count: u64,
+    
+    /// Access period granted to the buyer in seconds
+    pub access_period: u64,
+    
+    /// Timestamp after which the bounty can no longer be accepted
+    pub expires_at: i64,
+    
+    /// Token account escrowing the bounty funds
+    pub vault: Pubkey,
+    
+    /// Whether the bounty is still open
+    pub is_active: bool,
+    
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl Bounty {
+    pub const LEN: usize = 32 + 64 + 32 + 32 + 2 + 8 + 8 + 8 + 8 + 8 + 32 + 1 + 1;
+    
+    /// Longest bounty ID, in bytes, that fits the account
+    pub const MAX_BOUNTY_ID_LEN: usize = 64 - 4;
+    
+    /// Most data types that fit the account
+    pub const MAX_DATA_TYPES: usize = 32 - 4;
+    
+    /// Most device types that fit the account
+    pub const MAX_DEVICE_TYPES: usize = 32 - 4;
+}
+
+/// Record of a provider accepting a bounty
+#[account]
+pub struct BountyAcceptance {
+    /// Bounty accepted
+    pub bounty: Pubkey,
+    
+    /// Provider that accepted
+    pub provider: Pubkey,
+    
+    /// Acceptance timestamp
+    pub accepted_at: i64,
+    
+    /// Amount paid out of the bounty escrow
+    pub amount_paid: u64,
+}
+
+impl BountyAcceptance {
+    pub const LEN: usize = 32 + 32 + 8 + 8;
+}
+
+/// Auction kind enum
+pub enum AuctionKind {
+    English = 0,
+    Dutch = 1,
+}
+
+/// Auction selling exclusive access to a data listing
+#[account]
+pub struct Auction {
+    /// Provider running the auction
+    pub provider: Pubkey,
+    
+    /// Data listing being auctioned
+    pub listing_id: String,
+    
+    /// Auction ID, unique per listing
+    pub auction_id: u64,
+    
+    /// Auction kind (0: English, 1: Dutch)
+    pub kind: u8,
+    
+    /// Mint payments are made in
+    pub payment_mint: Pubkey,
+    
+    /// English: minimum first bid; Dutch: price at start
+    pub start_price: u64,
+    
+    /// Dutch: floor price reached at the end time
+    pub end_price: u64,
+    
+    /// English: minimum increment over the highest bid
+    pub min_increment: u64,
+    
+    /// English: bids this close to the end push the end time back by this much
+    pub extension_window: i64,
+    
+    /// Start timestamp
+    pub start_time: i64,
+    
+    /// End timestamp
+    pub end_time: i64,
+    
+    /// Highest bidder (English) or buyer (Dutch)
+    pub highest_bidder: Pubkey,
+    
+    /// Highest bid (English) or sale price (Dutch)
+    pub highest_bid: u64,
+    
+    /// Token account escrowing the highest bid
+    pub vault: Pubkey,
+    
+    /// Whether the auction has been settled or cancelled
+    pub settled: bool,
+    
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl Auction {
+    pub const LEN: usize = 32 + 64 + 8 + 1 + 32 + 8 + 8 + 8 + 8 + 8 + 8 + 32 + 8 + 32 + 1 + 1;
+    
+    /// Dutch auction price at a given timestamp, decaying linearly to the end price
+    pub fn dutch_price_at(&self, timestamp: i64) -> u64 {
+        if timestamp <= self.start_time {
+            return self.start_price;
+        }
+        if timestamp >= self.end_time {
+            return self.end_price;
+        }
+        
+        let elapsed = (timestamp - self.start_time) as u128;
+        let duration = (self.end_time - self.start_time) as u128;
+        let price_drop = (self.start_price - self.end_price) as u128;
+        
+        self.start_price - (price_drop * elapsed / duration) as u64
+    }
+}
+
+/// Cooperative pool selling many providers' data as one listing
+#[account]
+pub struct DataPool {
+    /// Curator who created the pool
+    pub curator: Pubkey,
+    
+    /// Unique pool ID, also the ID of the pooled listing
+    pub pool_id: String,
+    
+    /// Data types members can contribute
+    pub data_types: Vec<u8>,
+    
+    /// Token account collecting the members' share of revenue
+    pub revenue_vault: Pubkey,
+    
+    /// Sum of the quality-weighted contributions of all members
+    pub total_weight: u64,
+    
+    /// Accumulated revenue per unit of weight, scaled by ACC_REWARD_PRECISION
+    pub acc_revenue_per_weight: u128,
+    
+    /// Number of members
+    pub member_count: u64,
+    
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl DataPool {
+    pub const LEN: usize = 32 + 64 + 32 + 32 + 8 + 16 + 8 + 1;
+}
+
+/// Provider membership in a data pool
+#[account]
+pub struct PoolMembership {
+    /// Data pool joined
+    pub data_pool: Pubkey,
+    
+    /// Provider authority
+    pub provider: Pubkey,
+    
+    /// Quality-weighted contribution of committed submissions
+    pub weight: u64,
+    
+    /// Number of committed submissions
+    pub submission_count: u64,
+    
+    /// Accumulator value already accounted for, scaled by ACC_REWARD_PRECISION
+    pub reward_debt: u128,
+    
+    /// Revenue settled and not yet claimed
+    pub pending_revenue: u64,
+    
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl PoolMembership {
+    pub const LEN: usize = 32 + 32 + 8 + 8 + 16 + 8 + 1;
+}
+
+/// Record of a submission committed to a data pool
+#[account]
+pub struct PoolCommitment {
+    /// Data pool the submission was committed to
+    pub data_pool: Pubkey,
+    
+    /// Committed submission
+    pub data_submission: Pubkey,
+    
+    /// Weight the submission contributed
+    pub weight: u64,
+}
+
+impl PoolCommitment {
+    pub const LEN: usize = 32 + 32 + 8;
+}
+
+/// License record backing a transferable license token
+#[account]
+pub struct LicenseRecord {
+    /// Data listing the license grants access to
+    pub listing_id: String,
+    
+    /// Index of the license within the listing
+    pub license_index: u64,
+    
+    /// Supply-1 mint whose holder owns the license
+    pub license_mint: Pubkey,
+    
+    /// Timestamp when the license was purchased
+    pub purchased_at: i64,
+    
+    /// Timestamp when the license expires
+    pub expires_at: i64,
+    
+    /// Amount paid at the primary sale
+    pub amount_paid: u64,
+    
+    /// Whether the license is still valid
+    pub is_valid: bool,
+    
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl LicenseRecord {
+    pub const LEN: usize = 64 + 8 + 32 + 8 + 8 + 8 + 1 + 1;
 }
 
 // Import error code
-use crate::errors::BodyDfiError; 
\ No newline at end of file
+use crate::errors::BodyDfiError;
+use crate::token::token_state::TokenMint;
+use crate::config::config_state::{ProgramConfig, PAUSE_DATA, PAUSE_MARKETPLACE};
+use crate::governance::governance_state::{ParameterChange, Proposal}; 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn dutch_auction(start_price: u64, end_price: u64, start_time: i64, end_time: i64) -> Auction {
+        Auction {
+            provider: Pubkey::default(),
+            listing_id: String::from("listing"),
+            auction_id: 0,
+            kind: AuctionKind::Dutch as u8,
+            payment_mint: Pubkey::default(),
+            start_price,
+            end_price,
+            min_increment: 0,
+            extension_window: 0,
+            start_time,
+            end_time,
+            highest_bidder: Pubkey::default(),
+            highest_bid: 0,
+            vault: Pubkey::default(),
+            settled: false,
+            bump: 0,
+        }
+    }
+    
+    #[test]
+    fn dutch_price_starts_at_start_price() {
+        let auction = dutch_auction(1_000, 100, 1_000, 2_000);
+        assert_eq!(auction.dutch_price_at(1_000), 1_000);
+        assert_eq!(auction.dutch_price_at(0), 1_000);
+    }
+    
+    #[test]
+    fn dutch_price_ends_at_end_price() {
+        let auction = dutch_auction(1_000, 100, 1_000, 2_000);
+        assert_eq!(auction.dutch_price_at(2_000), 100);
+        assert_eq!(auction.dutch_price_at(i64::MAX), 100);
+    }
+    
+    #[test]
+    fn dutch_price_decays_linearly() {
+        let auction = dutch_auction(1_000, 100, 1_000, 2_000);
+        assert_eq!(auction.dutch_price_at(1_250), 775);
+        assert_eq!(auction.dutch_price_at(1_500), 550);
+        assert_eq!(auction.dutch_price_at(1_999), 101);
+    }
+    
+    #[test]
+    fn dutch_price_never_rises() {
+        let auction = dutch_auction(7_919, 13, 0, 997);
+        let mut previous = auction.dutch_price_at(0);
+        for timestamp in 1..=997 {
+            let price = auction.dutch_price_at(timestamp);
+            assert!(price <= previous);
+            assert!(price >= auction.end_price);
+            previous = price;
+        }
+    }
+    
+    fn full_listing(listing_id: &str) -> DataListing {
+        DataListing {
+            provider: Pubkey::default(),
+            listing_id: String::from(listing_id),
+            data_types: vec![DATA_TYPE_MOTION; DataListing::MAX_DATA_TYPES],
+            price_per_access: 1_000,
+            access_period: 86_400,
+            description: "d".repeat(DataListing::MAX_DESCRIPTION_LEN),
+            purchase_count: 0,
+            created_at: 0,
+            is_active: true,
+            latest_access_expiry: 0,
+            transferable_license: false,
+            royalty_bps: 0,
+            exclusive_until: 0,
+            payment_mint: Pubkey::default(),
+            is_native_sol: false,
+            listing_version: 0,
+            is_closed: false,
+        }
+    }
+    
+    #[test]
+    fn full_listing_fits_account() {
+        let listing = full_listing(&"l".repeat(DataListing::MAX_LISTING_ID_LEN));
+        assert_eq!(listing.try_to_vec().unwrap().len(), DataListing::LEN);
+    }
+    
+    #[test]
+    fn tombstone_len_matches_cleared_listing() {
+        for listing_id in ["", "listing", &"l".repeat(DataListing::MAX_LISTING_ID_LEN)] {
+            let mut listing = full_listing(listing_id);
+            listing.data_types = Vec::new();
+            listing.description = String::new();
+            assert_eq!(listing.try_to_vec().unwrap().len(), listing.tombstone_len());
+            assert!(listing.tombstone_len() < DataListing::LEN);
+        }
+    }
+}
@@ -0,0 +1,334 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer};
+use crate::errors::BodyDfiError;
+use crate::data_marketplace::data_state::*;
+use crate::data_marketplace::marketplace_operations::{calculate_fee_split, holder_fee_destination, transfer_fee_split};
+
+/// Update the escrow arbiter and dispute window
+pub fn update_escrow_settings(
+    ctx: Context<UpdateEscrowSettings>,
+    arbiter: Pubkey,
+    dispute_window: i64,
+) -> Result<()> {
+    require!(dispute_window >= 0, BodyDfiError::InvalidDisputeWindow);
+    
+    let marketplace_config = &mut ctx.accounts.marketplace_config;
+    marketplace_config.arbiter = arbiter;
+    marketplace_config.dispute_window = dispute_window;
+    
+    // Emit event
+    emit!(EscrowSettingsUpdatedEvent {
+        arbiter,
+        dispute_window,
+    });
+    
+    Ok(())
+}
+
+/// Purchase escrowed data access, failing if the listing changed since it was quoted
+pub fn purchase_data_access_escrowed(
+    ctx: Context<PurchaseDataAccessEscrowed>,
+    max_price: u64,
+    listing_version: u64,
+) -> Result<()> {
+    let buyer = &ctx.accounts.buyer;
+    let data_listing = &mut ctx.accounts.data_listing;
+    let data_access = &mut ctx.accounts.data_access;
+    let purchase_escrow = &mut ctx.accounts.purchase_escrow;
+    let current_time = ctx.accounts.clock.unix_timestamp;
+    
+    // Validate listing is active and sells plain access
+    require!(data_listing.is_open_for_sale(current_time), BodyDfiError::InvalidDataListing);
+    require!(!data_listing.transferable_license, BodyDfiError::LicenseRequired);
+    
+    // Protect the buyer against terms changing between quote and confirmation
+    require!(
+        data_listing.listing_version == listing_version,
+        BodyDfiError::ListingVersionMismatch
+    );
+    require!(data_listing.price_per_access <= max_price, BodyDfiError::PriceExceedsMax);
+    
+    // Move the full price into the escrow vault
+    let total_amount = data_listing.price_per_access;
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.buyer_token_account.to_account_info(),
+        to: ctx.accounts.escrow_vault.to_account_info(),
+        authority: buyer.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    
+    token::transfer(cpi_ctx, total_amount)?;
+    
+    // Access is granted immediately; only the payment is held back
+    data_access.buyer = buyer.key();
+    data_access.listing_id = data_listing.listing_id.clone();
+    data_access.purchased_at = current_time;
+    data_access.expires_at = current_time + data_listing.access_period as i64;
+    data_access.amount_paid = total_amount;
+    data_access.is_valid = true;
+    data_access.renewal_count = 0;
+    data_listing.latest_access_expiry = data_listing.latest_access_expiry.max(data_access.expires_at);
+    
+    // Initialize escrow
+    purchase_escrow.data_access = data_access.key();
+    purchase_escrow.buyer = buyer.key();
+    purchase_escrow.provider = data_listing.provider;
+    purchase_escrow.vault = ctx.accounts.escrow_vault.key();
+    purchase_escrow.amount = total_amount;
+    purchase_escrow.release_after = current_time
+        .checked_add(ctx.accounts.marketplace_config.dispute_window)
+        .unwrap();
+    purchase_escrow.delivery_confirmed = false;
+    purchase_escrow.status = EscrowStatus::Held as u8;
+    purchase_escrow.bump = ctx.bumps.purchase_escrow;
+    
+    // Update listing stats
+    data_listing.purchase_count = data_listing.purchase_count.checked_add(1).unwrap();
+    
+    // Emit event
+    emit!(EscrowedPurchaseEvent {
+        buyer: buyer.key(),
+        provider: data_listing.provider,
+        listing_id: data_listing.listing_id.clone(),
+        amount: total_amount,
+        release_after: purchase_escrow.release_after,
+        expires_at: data_access.expires_at,
+    });
+    
+    Ok(())
+}
+
+/// Buyer confirms delivery, allowing the provider to release early
+pub fn confirm_delivery(ctx: Context<EscrowBuyerAction>) -> Result<()> {
+    let purchase_escrow = &mut ctx.accounts.purchase_escrow;
+    
+    require!(
+        purchase_escrow.status == EscrowStatus::Held as u8,
+        BodyDfiError::InvalidEscrowState
+    );
+    
+    purchase_escrow.delivery_confirmed = true;
+    
+    // Emit event
+    emit!(DeliveryConfirmedEvent {
+        purchase_escrow: purchase_escrow.key(),
+        buyer: purchase_escrow.buyer,
+    });
+    
+    Ok(())
+}
+
+/// Buyer disputes a purchase, freezing the escrow until the arbiter resolves it
+pub fn open_dispute(ctx: Context<EscrowBuyerAction>) -> Result<()> {
+    let purchase_escrow = &mut ctx.accounts.purchase_escrow;
+    let current_time = ctx.accounts.clock.unix_timestamp;
+    
+    require!(
+        purchase_escrow.status == EscrowStatus::Held as u8,
+        BodyDfiError::InvalidEscrowState
+    );
+    require!(
+        !purchase_escrow.delivery_confirmed && current_time < purchase_escrow.release_after,
+        BodyDfiError::DisputeWindowClosed
+    );
+    
+    purchase_escrow.status = EscrowStatus::Disputed as u8;
+    
+    // Emit event
+    emit!(DisputeOpenedEvent {
+        purchase_escrow: purchase_escrow.key(),
+        buyer: purchase_escrow.buyer,
+        provider: purchase_escrow.provider,
+        amount: purchase_escrow.amount,
+    });
+    
+    Ok(())
+}
+
+/// Provider releases the escrowed payment after confirmation or timeout
+pub fn release_escrow(ctx: Context<ReleaseEscrow>) -> Result<()> {
+    let purchase_escrow = &ctx.accounts.purchase_escrow;
+    let current_time = ctx.accounts.clock.unix_timestamp;
+    
+    require!(
+        purchase_escrow.status == EscrowStatus::Held as u8,
+        BodyDfiError::InvalidEscrowState
+    );
+    require!(
+        purchase_escrow.delivery_confirmed || current_time >= purchase_escrow.release_after,
+        BodyDfiError::EscrowNotReleasable
+    );
+    
+    let amount = purchase_escrow.amount;
+    let fee_split = calculate_fee_split(amount, &ctx.accounts.marketplace_config)?;
+    
+    let data_access_key = purchase_escrow.data_access;
+    let seeds = &[
+        b"purchase-escrow".as_ref(),
+        data_access_key.as_ref(),
+        &[purchase_escrow.bump],
+    ];
+    let signer = &[&seeds[..]];
+    
+    transfer_fee_split(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.escrow_vault.to_account_info(),
+        ctx.accounts.purchase_escrow.to_account_info(),
+        ctx.accounts.provider_token_account.to_account_info(),
+        ctx.accounts.treasury_token_account.to_account_info(),
+        holder_fee_destination(&ctx.accounts.holder_rewards_vault, &ctx.accounts.treasury_token_account)?,
+        &fee_split,
+        signer,
+    )?;
+    
+    let purchase_escrow = &mut ctx.accounts.purchase_escrow;
+    purchase_escrow.amount = 0;
+    purchase_escrow.status = EscrowStatus::Released as u8;
+    
+    // Update provider stats
+    let data_provider = &mut ctx.accounts.data_provider;
+    data_provider.total_rewards = data_provider.total_rewards.checked_add(fee_split.provider_amount).unwrap();
+    
+    // Emit event
+    emit!(EscrowReleasedEvent {
+        purchase_escrow: purchase_escrow.key(),
+        provider: purchase_escrow.provider,
+        amount,
+        platform_fee: fee_split.platform_fee,
+        token_holder_fee: fee_split.token_holder_fee,
+        provider_amount: fee_split.provider_amount,
+    });
+    
+    Ok(())
+}
+
+/// Arbiter resolves a dispute with a full, partial or zero refund to the buyer
+pub fn resolve_dispute(ctx: Context<ResolveDispute>, refund_amount: u64) -> Result<()> {
+    let purchase_escrow = &ctx.accounts.purchase_escrow;
+    
+    require!(
+        purchase_escrow.status == EscrowStatus::Disputed as u8,
+        BodyDfiError::InvalidEscrowState
+    );
+    require!(
+        refund_amount <= purchase_escrow.amount,
+        BodyDfiError::InvalidRefundAmount
+    );
+    
+    let amount = purchase_escrow.amount;
+    let data_access_key = purchase_escrow.data_access;
+    let seeds = &[
+        b"purchase-escrow".as_ref(),
+        data_access_key.as_ref(),
+        &[purchase_escrow.bump],
+    ];
+    let signer = &[&seeds[..]];
+    
+    // Refund the buyer
+    if refund_amount > 0 {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.escrow_vault.to_account_info(),
+            to: ctx.accounts.buyer_token_account.to_account_info(),
+            authority: ctx.accounts.purchase_escrow.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer,
+        );
+    
+        token::transfer(cpi_ctx, refund_amount)?;
+    }
+    
+    // Settle the remainder with the normal fee split
+    let fee_split = calculate_fee_split(amount - refund_amount, &ctx.accounts.marketplace_config)?;
+    transfer_fee_split(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.escrow_vault.to_account_info(),
+        ctx.accounts.purchase_escrow.to_account_info(),
+        ctx.accounts.provider_token_account.to_account_info(),
+        ctx.accounts.treasury_token_account.to_account_info(),
+        holder_fee_destination(&ctx.accounts.holder_rewards_vault, &ctx.accounts.treasury_token_account)?,
+        &fee_split,
+        signer,
+    )?;
+    
+    // A full refund revokes the access that was granted
+    let data_access = &mut ctx.accounts.data_access;
+    data_access.amount_paid = amount - refund_amount;
+    if refund_amount == amount {
+        data_access.is_valid = false;
+    }
+    
+    let purchase_escrow = &mut ctx.accounts.purchase_escrow;
+    purchase_escrow.amount = 0;
+    purchase_escrow.status = EscrowStatus::Resolved as u8;
+    
+    // Update provider stats
+    let data_provider = &mut ctx.accounts.data_provider;
+    data_provider.total_rewards = data_provider.total_rewards.checked_add(fee_split.provider_amount).unwrap();
+    
+    // Emit event
+    emit!(DisputeResolvedEvent {
+        purchase_escrow: purchase_escrow.key(),
+        arbiter: ctx.accounts.arbiter.key(),
+        refund_amount,
+        provider_amount: fee_split.provider_amount,
+    });
+    
+    Ok(())
+}
+
+/// Event emitted when the escrow settings change
+#[event]
+pub struct EscrowSettingsUpdatedEvent {
+    pub arbiter: Pubkey,
+    pub dispute_window: i64,
+}
+
+/// Event emitted when data access is purchased through escrow
+#[event]
+pub struct EscrowedPurchaseEvent {
+    pub buyer: Pubkey,
+    pub provider: Pubkey,
+    pub listing_id: String,
+    pub amount: u64,
+    pub release_after: i64,
+    pub expires_at: i64,
+}
+
+/// Event emitted when the buyer confirms delivery
+#[event]
+pub struct DeliveryConfirmedEvent {
+    pub purchase_escrow: Pubkey,
+    pub buyer: Pubkey,
+}
+
+/// Event emitted when the buyer opens a dispute
+#[event]
+pub struct DisputeOpenedEvent {
+    pub purchase_escrow: Pubkey,
+    pub buyer: Pubkey,
+    pub provider: Pubkey,
+    pub amount: u64,
+}
+
+/// Event emitted when an escrowed payment is released to the provider
+#[event]
+pub struct EscrowReleasedEvent {
+    pub purchase_escrow: Pubkey,
+    pub provider: Pubkey,
+    pub amount: u64,
+    pub platform_fee: u64,
+    pub token_holder_fee: u64,
+    pub provider_amount: u64,
+}
+
+/// Event emitted when the arbiter resolves a dispute
+#[event]
+pub struct DisputeResolvedEvent {
+    pub purchase_escrow: Pubkey,
+    pub arbiter: Pubkey,
+    pub refund_amount: u64,
+    pub provider_amount: u64,
+}
@@ -0,0 +1,305 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{
+    self, spl_token::instruction::AuthorityType, FreezeAccount, MintTo, SetAuthority, ThawAccount, TokenAccount,
+    Transfer,
+};
+use crate::errors::BodyDfiError;
+use crate::data_marketplace::data_state::*;
+use crate::data_marketplace::marketplace_operations::{calculate_fee_split, holder_fee_destination, transfer_fee_split};
+use crate::token::token_state::BASIS_POINTS_DENOMINATOR;
+
+/// Enable or disable transferable licenses on a listing and set the resale royalty
+pub fn set_license_options(
+    ctx: Context<ManageDataListing>,
+    transferable_license: bool,
+    royalty_bps: u16,
+) -> Result<()> {
+    require!(
+        royalty_bps as u64 <= BASIS_POINTS_DENOMINATOR,
+        BodyDfiError::InvalidRoyalty
+    );
+    
+    let data_listing = &mut ctx.accounts.data_listing;
+    data_listing.transferable_license = transferable_license;
+    data_listing.royalty_bps = royalty_bps;
+    data_listing.listing_version = data_listing.listing_version.checked_add(1).unwrap();
+    
+    // Emit event
+    emit!(LicenseOptionsUpdatedEvent {
+        provider: data_listing.provider,
+        listing_id: data_listing.listing_id.clone(),
+        transferable_license,
+        royalty_bps,
+        listing_version: data_listing.listing_version,
+    });
+    
+    Ok(())
+}
+
+/// Purchase a supply-1 license token, failing if the listing changed since it was quoted
+pub fn purchase_license(ctx: Context<PurchaseLicense>, max_price: u64, listing_version: u64) -> Result<()> {
+    let data_listing = &ctx.accounts.data_listing;
+    let current_time = ctx.accounts.clock.unix_timestamp;
+    
+    // Validate listing
+    require!(data_listing.is_open_for_sale(current_time), BodyDfiError::InvalidDataListing);
+    require!(data_listing.transferable_license, BodyDfiError::LicenseNotEnabled);
+    
+    // Protect the buyer against terms changing between quote and confirmation
+    require!(
+        data_listing.listing_version == listing_version,
+        BodyDfiError::ListingVersionMismatch
+    );
+    require!(data_listing.price_per_access <= max_price, BodyDfiError::PriceExceedsMax);
+    
+    // Calculate fee splits
+    let total_amount = data_listing.price_per_access;
+    let fee_split = calculate_fee_split(total_amount, &ctx.accounts.marketplace_config)?;
+    
+    // Transfer each leg of the split from the buyer
+    transfer_fee_split(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.buyer_token_account.to_account_info(),
+        ctx.accounts.buyer.to_account_info(),
+        ctx.accounts.provider_token_account.to_account_info(),
+        ctx.accounts.treasury_token_account.to_account_info(),
+        holder_fee_destination(&ctx.accounts.holder_rewards_vault, &ctx.accounts.treasury_token_account)?,
+        &fee_split,
+        &[],
+    )?;
+    
+    let license_index = data_listing.purchase_count;
+    let listing_id = data_listing.listing_id.clone();
+    let license_index_bytes = license_index.to_le_bytes();
+    let seeds = &[
+        b"license".as_ref(),
+        listing_id.as_bytes(),
+        license_index_bytes.as_ref(),
+        &[ctx.bumps.license_record],
+    ];
+    let signer = &[&seeds[..]];
+    
+    // Mint the single license token to the buyer
+    let cpi_accounts = MintTo {
+        mint: ctx.accounts.license_mint.to_account_info(),
+        to: ctx.accounts.buyer_license_account.to_account_info(),
+        authority: ctx.accounts.license_record.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer,
+    );
+    
+    token::mint_to(cpi_ctx, 1)?;
+    
+    // Fix the supply at one
+    let cpi_accounts = SetAuthority {
+        current_authority: ctx.accounts.license_record.to_account_info(),
+        account_or_mint: ctx.accounts.license_mint.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer,
+    );
+    
+    token::set_authority(cpi_ctx, AuthorityType::MintTokens, None)?;
+    
+    // Keep the token frozen so it only moves through resell_license and its royalty
+    let cpi_accounts = FreezeAccount {
+        account: ctx.accounts.buyer_license_account.to_account_info(),
+        mint: ctx.accounts.license_mint.to_account_info(),
+        authority: ctx.accounts.license_record.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer,
+    );
+    
+    token::freeze_account(cpi_ctx)?;
+    
+    // Initialize license record
+    let data_listing = &mut ctx.accounts.data_listing;
+    let license_record = &mut ctx.accounts.license_record;
+    license_record.listing_id = listing_id;
+    license_record.license_index = license_index;
+    license_record.license_mint = ctx.accounts.license_mint.key();
+    license_record.purchased_at = current_time;
+    license_record.expires_at = current_time + data_listing.access_period as i64;
+    license_record.amount_paid = total_amount;
+    license_record.is_valid = true;
+    license_record.bump = ctx.bumps.license_record;
+    
+    // Update listing stats
+    data_listing.latest_access_expiry = data_listing.latest_access_expiry.max(license_record.expires_at);
+    data_listing.purchase_count = data_listing.purchase_count.checked_add(1).unwrap();
+    
+    // Update provider stats
+    let data_provider = &mut ctx.accounts.data_provider;
+    data_provider.total_rewards = data_provider.total_rewards.checked_add(fee_split.provider_amount).unwrap();
+    
+    // Emit event
+    emit!(LicensePurchasedEvent {
+        buyer: ctx.accounts.buyer.key(),
+        listing_id: license_record.listing_id.clone(),
+        license_mint: license_record.license_mint,
+        amount_paid: total_amount,
+        provider_amount: fee_split.provider_amount,
+        expires_at: license_record.expires_at,
+    });
+    
+    Ok(())
+}
+
+/// Resell a license token, paying the provider royalty out of the sale price
+pub fn resell_license(ctx: Context<ResellLicense>, price: u64) -> Result<()> {
+    let data_listing = &ctx.accounts.data_listing;
+    let license_record = &ctx.accounts.license_record;
+    let current_time = ctx.accounts.clock.unix_timestamp;
+    
+    // Only unexpired licenses can be resold, to someone else
+    require!(license_record.is_valid, BodyDfiError::InvalidLicense);
+    require!(current_time < license_record.expires_at, BodyDfiError::AccessExpired);
+    require!(
+        ctx.accounts.seller.key() != ctx.accounts.buyer.key(),
+        BodyDfiError::InvalidAuthority
+    );
+    
+    let royalty = (price as u128)
+        .checked_mul(data_listing.royalty_bps as u128)
+        .unwrap()
+        .checked_div(BASIS_POINTS_DENOMINATOR as u128)
+        .unwrap() as u64;
+    let seller_amount = price - royalty;
+    
+    let payments = [
+        (ctx.accounts.provider_token_account.to_account_info(), royalty),
+        (ctx.accounts.seller_token_account.to_account_info(), seller_amount),
+    ];
+    
+    for (to, amount) in payments {
+        if amount == 0 {
+            continue;
+        }
+    
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.buyer_token_account.to_account_info(),
+            to,
+            authority: ctx.accounts.buyer.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    
+        token::transfer(cpi_ctx, amount)?;
+    }
+    
+    let listing_id = license_record.listing_id.clone();
+    let license_index_bytes = license_record.license_index.to_le_bytes();
+    let seeds = &[
+        b"license".as_ref(),
+        listing_id.as_bytes(),
+        license_index_bytes.as_ref(),
+        &[license_record.bump],
+    ];
+    let signer = &[&seeds[..]];
+    
+    // Thaw the seller's token for the sale
+    let cpi_accounts = ThawAccount {
+        account: ctx.accounts.seller_license_account.to_account_info(),
+        mint: ctx.accounts.license_mint.to_account_info(),
+        authority: ctx.accounts.license_record.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer,
+    );
+    
+    token::thaw_account(cpi_ctx)?;
+    
+    // Move the license token to the buyer
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.seller_license_account.to_account_info(),
+        to: ctx.accounts.buyer_license_account.to_account_info(),
+        authority: ctx.accounts.seller.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    
+    token::transfer(cpi_ctx, 1)?;
+    
+    // Freeze it again in the buyer's account
+    let cpi_accounts = FreezeAccount {
+        account: ctx.accounts.buyer_license_account.to_account_info(),
+        mint: ctx.accounts.license_mint.to_account_info(),
+        authority: ctx.accounts.license_record.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer,
+    );
+    
+    token::freeze_account(cpi_ctx)?;
+    
+    // Emit event
+    emit!(LicenseResoldEvent {
+        seller: ctx.accounts.seller.key(),
+        buyer: ctx.accounts.buyer.key(),
+        license_mint: license_record.license_mint,
+        price,
+        royalty,
+    });
+    
+    Ok(())
+}
+
+/// Check that `holder` currently holds a valid, unexpired license through `holder_license_account`
+pub fn check_license_access(
+    license_record: &LicenseRecord,
+    holder_license_account: &TokenAccount,
+    holder: Pubkey,
+    current_time: i64,
+) -> Result<()> {
+    require!(
+        holder_license_account.owner == holder
+            && holder_license_account.mint == license_record.license_mint
+            && holder_license_account.amount == 1,
+        BodyDfiError::InvalidLicense
+    );
+    require!(license_record.is_valid, BodyDfiError::InvalidLicense);
+    require!(current_time < license_record.expires_at, BodyDfiError::AccessExpired);
+    
+    Ok(())
+}
+
+/// Event emitted when a listing's license options change
+#[event]
+pub struct LicenseOptionsUpdatedEvent {
+    pub provider: Pubkey,
+    pub listing_id: String,
+    pub transferable_license: bool,
+    pub royalty_bps: u16,
+    pub listing_version: u64,
+}
+
+/// Event emitted when a license token is purchased
+#[event]
+pub struct LicensePurchasedEvent {
+    pub buyer: Pubkey,
+    pub listing_id: String,
+    pub license_mint: Pubkey,
+    pub amount_paid: u64,
+    pub provider_amount: u64,
+    pub expires_at: i64,
+}
+
+/// Event emitted when a license token is resold
+#[event]
+pub struct LicenseResoldEvent {
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub license_mint: Pubkey,
+    pub price: u64,
+    pub royalty: u64,
+}
@@ -1,25 +1,248 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Transfer};
+use anchor_lang::system_program;
+use anchor_spl::token::{self, TokenAccount, Transfer};
 use crate::errors::BodyDfiError;
 use crate::data_marketplace::data_state::*;
+use crate::governance::governance_operations::execute_parameter_change;
+use crate::governance::governance_state::{PARAMETER_KIND_MARKETPLACE_FEES, PARAMETER_KIND_PAYMENT_MINT};
+use crate::token::token_state::BASIS_POINTS_DENOMINATOR;
 
-/// Platform fee percentage (15%)
-const PLATFORM_FEE_PERCENTAGE: u64 = 15;
-const TOKEN_HOLDER_FEE_PERCENTAGE: u64 = 15;
-const PROVIDER_FEE_PERCENTAGE: u64 = 70;
-const PERCENTAGE_DENOMINATOR: u64 = 100;
+/// Default fee split in basis points (15% / 15% / 70%)
+pub const DEFAULT_PLATFORM_FEE_BPS: u16 = 1_500;
+pub const DEFAULT_TOKEN_HOLDER_FEE_BPS: u16 = 1_500;
+pub const DEFAULT_PROVIDER_FEE_BPS: u16 = 7_000;
 
-/// Create a data marketplace listing
-pub fn create_data_listing(
-    ctx: Context<CreateDataListing>,
-    listing_id: String,
-    data_types: Vec<u8>,
+/// Default dispute window for escrowed purchases (3 days)
+pub const DEFAULT_DISPUTE_WINDOW: i64 = 3 * 24 * 60 * 60;
+
+/// Split of a payment between the platform, token holders and the provider
+pub struct FeeSplit {
+    pub platform_fee: u64,
+    pub token_holder_fee: u64,
+    pub provider_amount: u64,
+}
+
+/// Calculate the fee split of a payment; rounding dust goes to the provider
+pub fn calculate_fee_split(
+    total_amount: u64,
+    marketplace_config: &MarketplaceConfig,
+) -> Result<FeeSplit> {
+    let platform_fee = (total_amount as u128)
+        .checked_mul(marketplace_config.platform_fee_bps as u128)
+        .unwrap()
+        .checked_div(BASIS_POINTS_DENOMINATOR as u128)
+        .unwrap() as u64;
+    let token_holder_fee = (total_amount as u128)
+        .checked_mul(marketplace_config.token_holder_fee_bps as u128)
+        .unwrap()
+        .checked_div(BASIS_POINTS_DENOMINATOR as u128)
+        .unwrap() as u64;
+    let provider_amount = total_amount
+        .checked_sub(platform_fee)
+        .and_then(|amount| amount.checked_sub(token_holder_fee))
+        .ok_or(BodyDfiError::InvalidFeeParameters)?;
+    
+    Ok(FeeSplit {
+        platform_fee,
+        token_holder_fee,
+        provider_amount,
+    })
+}
+
+/// Validate that fee parameters sum to 100%
+pub fn validate_fee_parameters(
+    platform_fee_bps: u16,
+    token_holder_fee_bps: u16,
+    provider_fee_bps: u16,
+) -> Result<()> {
+    let total_bps = platform_fee_bps as u64 + token_holder_fee_bps as u64 + provider_fee_bps as u64;
+    require!(
+        total_bps == BASIS_POINTS_DENOMINATOR,
+        BodyDfiError::InvalidFeeParameters
+    );
+    
+    Ok(())
+}
+
+/// Initialize the marketplace config with the default fee split
+pub fn initialize_marketplace_config(ctx: Context<InitializeMarketplaceConfig>) -> Result<()> {
+    let marketplace_config = &mut ctx.accounts.marketplace_config;
+    
+    marketplace_config.platform_fee_bps = DEFAULT_PLATFORM_FEE_BPS;
+    marketplace_config.token_holder_fee_bps = DEFAULT_TOKEN_HOLDER_FEE_BPS;
+    marketplace_config.provider_fee_bps = DEFAULT_PROVIDER_FEE_BPS;
+    marketplace_config.arbiter = ctx.accounts.program_config.admin;
+    marketplace_config.dispute_window = DEFAULT_DISPUTE_WINDOW;
+    marketplace_config.bump = ctx.bumps.marketplace_config;
+    
+    Ok(())
+}
+
+/// Apply new fee parameters from a passed parameter change proposal
+pub fn execute_fee_parameter_change(ctx: Context<ExecuteFeeParameterChange>) -> Result<()> {
+    let parameter_change = &ctx.accounts.parameter_change;
+    execute_parameter_change(
+        &mut ctx.accounts.proposal,
+        parameter_change,
+        PARAMETER_KIND_MARKETPLACE_FEES,
+    )?;
+    
+    // Values are platform, token holder and provider basis points
+    let new_platform_fee_bps = u16::try_from(parameter_change.values[0])
+        .map_err(|_| BodyDfiError::InvalidFeeParameters)?;
+    let new_token_holder_fee_bps = u16::try_from(parameter_change.values[1])
+        .map_err(|_| BodyDfiError::InvalidFeeParameters)?;
+    let new_provider_fee_bps = u16::try_from(parameter_change.values[2])
+        .map_err(|_| BodyDfiError::InvalidFeeParameters)?;
+    validate_fee_parameters(new_platform_fee_bps, new_token_holder_fee_bps, new_provider_fee_bps)?;
+    
+    let marketplace_config = &mut ctx.accounts.marketplace_config;
+    
+    // Emit event with previous and new values
+    emit!(MarketplaceFeesUpdatedEvent {
+        proposal_id: parameter_change.proposal_id,
+        previous_platform_fee_bps: marketplace_config.platform_fee_bps,
+        previous_token_holder_fee_bps: marketplace_config.token_holder_fee_bps,
+        previous_provider_fee_bps: marketplace_config.provider_fee_bps,
+        platform_fee_bps: new_platform_fee_bps,
+        token_holder_fee_bps: new_token_holder_fee_bps,
+        provider_fee_bps: new_provider_fee_bps,
+    });
+    
+    marketplace_config.platform_fee_bps = new_platform_fee_bps;
+    marketplace_config.token_holder_fee_bps = new_token_holder_fee_bps;
+    marketplace_config.provider_fee_bps = new_provider_fee_bps;
+    
+    Ok(())
+}
+
+/// Allow or disallow a payment mint from a passed parameter change proposal
+pub fn execute_payment_mint_change(ctx: Context<ExecutePaymentMintChange>) -> Result<()> {
+    let parameter_change = &ctx.accounts.parameter_change;
+    execute_parameter_change(
+        &mut ctx.accounts.proposal,
+        parameter_change,
+        PARAMETER_KIND_PAYMENT_MINT,
+    )?;
+    
+    // Target is the mint, first value is 1 to allow and 0 to disallow
+    let is_allowed = match parameter_change.values[0] {
+        0 => false,
+        1 => true,
+        _ => return err!(BodyDfiError::InvalidPaymentMint),
+    };
+    
+    let allowed_payment_mint = &mut ctx.accounts.allowed_payment_mint;
+    allowed_payment_mint.mint = ctx.accounts.payment_mint.key();
+    allowed_payment_mint.is_allowed = is_allowed;
+    allowed_payment_mint.bump = ctx.bumps.allowed_payment_mint;
+    
+    // Emit event
+    emit!(PaymentMintUpdatedEvent {
+        proposal_id: parameter_change.proposal_id,
+        mint: allowed_payment_mint.mint,
+        is_allowed,
+    });
+    
+    Ok(())
+}
+
+/// Token account receiving the token holder fee; payments in a mint the holder
+/// rewards vault does not hold are rejected rather than redirected
+pub fn holder_fee_destination<'info>(
+    holder_rewards_vault: &Account<'info, TokenAccount>,
+    treasury_token_account: &Account<'info, TokenAccount>,
+) -> Result<AccountInfo<'info>> {
+    require!(
+        holder_rewards_vault.mint == treasury_token_account.mint,
+        BodyDfiError::HolderRewardsVaultMismatch
+    );
+    
+    Ok(holder_rewards_vault.to_account_info())
+}
+
+/// Transfer each leg of a fee split in lamports; there is no lamport holder
+/// rewards vault, so payments carrying a token holder fee are rejected
+pub fn transfer_fee_split_lamports<'info>(
+    system_program: AccountInfo<'info>,
+    from: AccountInfo<'info>,
+    provider_to: AccountInfo<'info>,
+    treasury_to: AccountInfo<'info>,
+    fee_split: &FeeSplit,
+) -> Result<()> {
+    require!(
+        fee_split.token_holder_fee == 0,
+        BodyDfiError::HolderRewardsVaultMismatch
+    );
+    
+    let legs = [
+        (provider_to, fee_split.provider_amount),
+        (treasury_to, fee_split.platform_fee),
+    ];
+    
+    for (to, amount) in legs {
+        if amount == 0 {
+            continue;
+        }
+        
+        let cpi_accounts = system_program::Transfer {
+            from: from.clone(),
+            to,
+        };
+        let cpi_ctx = CpiContext::new(system_program.clone(), cpi_accounts);
+        
+        system_program::transfer(cpi_ctx, amount)?;
+    }
+    
+    Ok(())
+}
+
+/// Transfer each leg of a fee split out of a token account
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_fee_split<'info>(
+    token_program: AccountInfo<'info>,
+    from: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    provider_to: AccountInfo<'info>,
+    treasury_to: AccountInfo<'info>,
+    holder_rewards_to: AccountInfo<'info>,
+    fee_split: &FeeSplit,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let legs = [
+        (provider_to, fee_split.provider_amount),
+        (treasury_to, fee_split.platform_fee),
+        (holder_rewards_to, fee_split.token_holder_fee),
+    ];
+    
+    for (to, amount) in legs {
+        if amount == 0 {
+            continue;
+        }
+        
+        let cpi_accounts = Transfer {
+            from: from.clone(),
+            to,
+            authority: authority.clone(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(token_program.clone(), cpi_accounts, signer_seeds);
+        
+        token::transfer(cpi_ctx, amount)?;
+    }
+    
+    Ok(())
+}
+
+/// Validate the data types, price, access period and description of a listing
+pub(crate) fn validate_listing_terms(
+    data_types: &[u8],
     price_per_access: u64,
     access_period: u64,
-    description: String,
+    description: &str,
 ) -> Result<()> {
-    // Validate input
     require!(!data_types.is_empty(), BodyDfiError::InvalidDataType);
+    require!(data_types.len() <= DataListing::MAX_DATA_TYPES, BodyDfiError::InputTooLong);
+    require!(description.len() <= DataListing::MAX_DESCRIPTION_LEN, BodyDfiError::InputTooLong);
     require!(price_per_access > 0, BodyDfiError::InvalidDataListing);
     require!(access_period > 0, BodyDfiError::InvalidDataListing);
     
@@ -31,6 +254,22 @@ pub fn create_data_listing(
         );
     }
     
+    Ok(())
+}
+
+/// Create a data marketplace listing
+pub fn create_data_listing(
+    ctx: Context<CreateDataListing>,
+    listing_id: String,
+    data_types: Vec<u8>,
+    price_per_access: u64,
+    access_period: u64,
+    description: String,
+) -> Result<()> {
+    // Validate input
+    require!(listing_id.len() <= DataListing::MAX_LISTING_ID_LEN, BodyDfiError::InputTooLong);
+    validate_listing_terms(&data_types, price_per_access, access_period, &description)?;
+    
     let data_listing = &mut ctx.accounts.data_listing;
     let data_provider = &ctx.accounts.data_provider;
     let current_time = ctx.accounts.clock.unix_timestamp;
@@ -45,6 +284,24 @@ pub fn create_data_listing(
     data_listing.purchase_count = 0;
     data_listing.created_at = current_time;
     data_listing.is_active = true;
+    data_listing.latest_access_expiry = 0;
+    data_listing.transferable_license = false;
+    data_listing.royalty_bps = 0;
+    data_listing.exclusive_until = 0;
+    data_listing.listing_version = 0;
+    data_listing.is_closed = false;
+    
+    // Price in the allowlisted mint, or in SOL when none is given
+    match &ctx.accounts.allowed_payment_mint {
+        Some(allowed_payment_mint) => {
+            data_listing.payment_mint = allowed_payment_mint.mint;
+            data_listing.is_native_sol = false;
+        }
+        None => {
+            data_listing.payment_mint = Pubkey::default();
+            data_listing.is_native_sol = true;
+        }
+    }
     
     // Emit event
     emit!(DataListingCreatedEvent {
@@ -52,15 +309,18 @@ pub fn create_data_listing(
         listing_id: data_listing.listing_id.clone(),
         price_per_access,
         access_period,
+        payment_mint: data_listing.payment_mint,
+        is_native_sol: data_listing.is_native_sol,
     });
     
     Ok(())
 }
 
-/// Purchase data access
+/// Purchase data access, failing if the listing changed since it was quoted
 pub fn purchase_data_access(
     ctx: Context<PurchaseDataAccess>,
-    listing_id: String,
+    max_price: u64,
+    listing_version: u64,
 ) -> Result<()> {
     let buyer = &ctx.accounts.buyer;
     let data_listing = &mut ctx.accounts.data_listing;
@@ -68,54 +328,90 @@ pub fn purchase_data_access(
     let data_access = &mut ctx.accounts.data_access;
     let current_time = ctx.accounts.clock.unix_timestamp;
     
-    // Validate listing is active
-    require!(data_listing.is_active, BodyDfiError::InvalidDataListing);
+    // Validate listing is active and sells plain access
+    require!(data_listing.is_open_for_sale(current_time), BodyDfiError::InvalidDataListing);
+    require!(!data_listing.transferable_license, BodyDfiError::LicenseRequired);
+    
+    // Protect the buyer against terms changing between quote and confirmation
+    require!(
+        data_listing.listing_version == listing_version,
+        BodyDfiError::ListingVersionMismatch
+    );
+    require!(data_listing.price_per_access <= max_price, BodyDfiError::PriceExceedsMax);
     
     // Calculate fee splits
     let total_amount = data_listing.price_per_access;
-    let platform_fee = total_amount
-        .checked_mul(PLATFORM_FEE_PERCENTAGE)
-        .unwrap()
-        .checked_div(PERCENTAGE_DENOMINATOR)
-        .unwrap();
-    let token_holder_fee = total_amount
-        .checked_mul(TOKEN_HOLDER_FEE_PERCENTAGE)
-        .unwrap()
-        .checked_div(PERCENTAGE_DENOMINATOR)
-        .unwrap();
-    let provider_amount = total_amount
-        .checked_mul(PROVIDER_FEE_PERCENTAGE)
-        .unwrap()
-        .checked_div(PERCENTAGE_DENOMINATOR)
-        .unwrap();
-    
-    // Transfer tokens from buyer to provider
-    let cpi_accounts = Transfer {
-        from: ctx.accounts.buyer_token_account.to_account_info(),
-        to: ctx.accounts.provider_token_account.to_account_info(),
-        authority: buyer.to_account_info(),
-    };
+    let fee_split = calculate_fee_split(total_amount, &ctx.accounts.marketplace_config)?;
     
-    let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+    // Transfer each leg of the split from the buyer, in SOL or in the listing's mint
+    if data_listing.is_native_sol {
+        let provider_wallet = ctx
+            .accounts
+            .provider_wallet
+            .as_ref()
+            .ok_or(BodyDfiError::InvalidDataProvider)?;
+        let treasury_wallet = ctx
+            .accounts
+            .treasury_wallet
+            .as_ref()
+            .ok_or(BodyDfiError::InvalidTokenAccount)?;
     
-    // For simplicity in this example, we're transferring directly to the provider
-    // In a production system, we would handle platform fees and token holder fees
-    token::transfer(cpi_ctx, provider_amount)?;
+        transfer_fee_split_lamports(
+            ctx.accounts.system_program.to_account_info(),
+            buyer.to_account_info(),
+            provider_wallet.to_account_info(),
+            treasury_wallet.to_account_info(),
+            &fee_split,
+        )?;
+    } else {
+        let buyer_token_account = ctx
+            .accounts
+            .buyer_token_account
+            .as_ref()
+            .ok_or(BodyDfiError::InvalidTokenAccount)?;
+        let provider_token_account = ctx
+            .accounts
+            .provider_token_account
+            .as_ref()
+            .ok_or(BodyDfiError::InvalidTokenAccount)?;
+        let treasury_token_account = ctx
+            .accounts
+            .treasury_token_account
+            .as_ref()
+            .ok_or(BodyDfiError::InvalidTokenAccount)?;
+        let holder_rewards_vault = ctx
+            .accounts
+            .holder_rewards_vault
+            .as_ref()
+            .ok_or(BodyDfiError::InvalidTokenAccount)?;
+    
+        transfer_fee_split(
+            ctx.accounts.token_program.to_account_info(),
+            buyer_token_account.to_account_info(),
+            buyer.to_account_info(),
+            provider_token_account.to_account_info(),
+            treasury_token_account.to_account_info(),
+            holder_fee_destination(holder_rewards_vault, treasury_token_account)?,
+            &fee_split,
+            &[],
+        )?;
+    }
     
     // Initialize data access
     data_access.buyer = buyer.key();
-    data_access.listing_id = listing_id;
+    data_access.listing_id = data_listing.listing_id.clone();
     data_access.purchased_at = current_time;
     data_access.expires_at = current_time + data_listing.access_period as i64;
     data_access.amount_paid = total_amount;
     data_access.is_valid = true;
+    data_access.renewal_count = 0;
+    data_listing.latest_access_expiry = data_listing.latest_access_expiry.max(data_access.expires_at);
     
     // Update listing stats
     data_listing.purchase_count = data_listing.purchase_count.checked_add(1).unwrap();
     
     // Update provider stats
-    data_provider.total_rewards = data_provider.total_rewards.checked_add(provider_amount).unwrap();
+    data_provider.total_rewards = data_provider.total_rewards.checked_add(fee_split.provider_amount).unwrap();
     
     // Emit event
     emit!(DataAccessPurchasedEvent {
@@ -123,7 +419,211 @@ pub fn purchase_data_access(
         provider: data_provider.authority,
         listing_id: data_listing.listing_id.clone(),
         amount_paid: total_amount,
+        platform_fee: fee_split.platform_fee,
+        token_holder_fee: fee_split.token_holder_fee,
+        provider_amount: fee_split.provider_amount,
+        expires_at: data_access.expires_at,
+    });
+    
+    Ok(())
+}
+
+/// Renew data access at the listing's current price, failing if the listing changed since it was quoted
+pub fn renew_data_access(ctx: Context<RenewDataAccess>, max_price: u64, listing_version: u64) -> Result<()> {
+    let buyer = &ctx.accounts.buyer;
+    let data_listing = &mut ctx.accounts.data_listing;
+    let data_provider = &mut ctx.accounts.data_provider;
+    let data_access = &mut ctx.accounts.data_access;
+    let current_time = ctx.accounts.clock.unix_timestamp;
+    
+    // Validate listing is active and access was not revoked; existing holders
+    // are not shut out by an auction's exclusivity period
+    require!(data_listing.is_active, BodyDfiError::InvalidDataListing);
+    require!(data_access.is_valid, BodyDfiError::AccessRevoked);
+    
+    // Protect the buyer against terms changing between quote and confirmation
+    require!(
+        data_listing.listing_version == listing_version,
+        BodyDfiError::ListingVersionMismatch
+    );
+    require!(data_listing.price_per_access <= max_price, BodyDfiError::PriceExceedsMax);
+    
+    // Calculate fee splits
+    let total_amount = data_listing.price_per_access;
+    let fee_split = calculate_fee_split(total_amount, &ctx.accounts.marketplace_config)?;
+    
+    // Transfer each leg of the split from the buyer, in SOL or in the listing's mint
+    if data_listing.is_native_sol {
+        let provider_wallet = ctx
+            .accounts
+            .provider_wallet
+            .as_ref()
+            .ok_or(BodyDfiError::InvalidDataProvider)?;
+        let treasury_wallet = ctx
+            .accounts
+            .treasury_wallet
+            .as_ref()
+            .ok_or(BodyDfiError::InvalidTokenAccount)?;
+    
+        transfer_fee_split_lamports(
+            ctx.accounts.system_program.to_account_info(),
+            buyer.to_account_info(),
+            provider_wallet.to_account_info(),
+            treasury_wallet.to_account_info(),
+            &fee_split,
+        )?;
+    } else {
+        let buyer_token_account = ctx
+            .accounts
+            .buyer_token_account
+            .as_ref()
+            .ok_or(BodyDfiError::InvalidTokenAccount)?;
+        let provider_token_account = ctx
+            .accounts
+            .provider_token_account
+            .as_ref()
+            .ok_or(BodyDfiError::InvalidTokenAccount)?;
+        let treasury_token_account = ctx
+            .accounts
+            .treasury_token_account
+            .as_ref()
+            .ok_or(BodyDfiError::InvalidTokenAccount)?;
+        let holder_rewards_vault = ctx
+            .accounts
+            .holder_rewards_vault
+            .as_ref()
+            .ok_or(BodyDfiError::InvalidTokenAccount)?;
+    
+        transfer_fee_split(
+            ctx.accounts.token_program.to_account_info(),
+            buyer_token_account.to_account_info(),
+            buyer.to_account_info(),
+            provider_token_account.to_account_info(),
+            treasury_token_account.to_account_info(),
+            holder_fee_destination(holder_rewards_vault, treasury_token_account)?,
+            &fee_split,
+            &[],
+        )?;
+    }
+    
+    // Extend from the later of now and the current expiry
+    let renewed_from = current_time.max(data_access.expires_at);
+    data_access.expires_at = renewed_from + data_listing.access_period as i64;
+    data_access.amount_paid = data_access.amount_paid.checked_add(total_amount).unwrap();
+    data_access.renewal_count = data_access.renewal_count.checked_add(1).unwrap();
+    data_listing.latest_access_expiry = data_listing.latest_access_expiry.max(data_access.expires_at);
+    
+    // Update provider stats
+    data_provider.total_rewards = data_provider.total_rewards.checked_add(fee_split.provider_amount).unwrap();
+    
+    // Emit event
+    emit!(DataAccessRenewedEvent {
+        buyer: buyer.key(),
+        provider: data_provider.authority,
+        listing_id: data_listing.listing_id.clone(),
+        amount_paid: total_amount,
+        platform_fee: fee_split.platform_fee,
+        token_holder_fee: fee_split.token_holder_fee,
+        provider_amount: fee_split.provider_amount,
         expires_at: data_access.expires_at,
+        renewal_count: data_access.renewal_count,
+    });
+    
+    Ok(())
+}
+
+/// Update the terms of a data listing
+pub fn update_data_listing(
+    ctx: Context<ManageDataListing>,
+    data_types: Vec<u8>,
+    price_per_access: u64,
+    access_period: u64,
+    description: String,
+) -> Result<()> {
+    update_listing_terms(
+        &mut ctx.accounts.data_listing,
+        data_types,
+        price_per_access,
+        access_period,
+        description,
+    )
+}
+
+/// Pause or reactivate a data listing
+pub fn set_listing_active(ctx: Context<ManageDataListing>, is_active: bool) -> Result<()> {
+    set_listing_status(&mut ctx.accounts.data_listing, is_active);
+    
+    Ok(())
+}
+
+/// Close a data listing once all access granted on it has expired
+pub fn close_data_listing(ctx: Context<CloseDataListing>) -> Result<()> {
+    retire_listing(&mut ctx.accounts.data_listing, ctx.accounts.clock.unix_timestamp)
+}
+
+/// Validate and apply new terms to a listing, bumping its version
+pub(crate) fn update_listing_terms(
+    data_listing: &mut DataListing,
+    data_types: Vec<u8>,
+    price_per_access: u64,
+    access_period: u64,
+    description: String,
+) -> Result<()> {
+    validate_listing_terms(&data_types, price_per_access, access_period, &description)?;
+    
+    data_listing.data_types = data_types;
+    data_listing.price_per_access = price_per_access;
+    data_listing.access_period = access_period;
+    data_listing.description = description;
+    data_listing.listing_version = data_listing.listing_version.checked_add(1).unwrap();
+    
+    // Emit event
+    emit!(DataListingUpdatedEvent {
+        provider: data_listing.provider,
+        listing_id: data_listing.listing_id.clone(),
+        price_per_access,
+        access_period,
+        listing_version: data_listing.listing_version,
+    });
+    
+    Ok(())
+}
+
+/// Pause or reactivate a listing, bumping its version
+pub(crate) fn set_listing_status(data_listing: &mut DataListing, is_active: bool) {
+    data_listing.is_active = is_active;
+    data_listing.listing_version = data_listing.listing_version.checked_add(1).unwrap();
+    
+    // Emit event
+    emit!(DataListingStatusChangedEvent {
+        provider: data_listing.provider,
+        listing_id: data_listing.listing_id.clone(),
+        is_active,
+        listing_version: data_listing.listing_version,
+    });
+}
+
+/// Permanently close a listing once all access granted on it has expired; the
+/// account context shrinks it to `DataListing::tombstone_len` and returns the rest of the rent
+pub(crate) fn retire_listing(data_listing: &mut DataListing, current_time: i64) -> Result<()> {
+    require!(
+        current_time >= data_listing.latest_access_expiry,
+        BodyDfiError::ListingHasActiveAccess
+    );
+    
+    // Rather than closing the account outright, keep a tombstone so the ID cannot
+    // be re-created and matched against data access accounts from the old listing
+    data_listing.data_types = Vec::new();
+    data_listing.description = String::new();
+    data_listing.is_active = false;
+    data_listing.is_closed = true;
+    data_listing.listing_version = data_listing.listing_version.checked_add(1).unwrap();
+    
+    // Emit event
+    emit!(DataListingClosedEvent {
+        provider: data_listing.provider,
+        listing_id: data_listing.listing_id.clone(),
+        purchase_count: data_listing.purchase_count,
     });
     
     Ok(())
@@ -136,6 +636,43 @@ pub struct DataListingCreatedEvent {
     pub listing_id: String,
     pub price_per_access: u64,
     pub access_period: u64,
+    pub payment_mint: Pubkey,
+    pub is_native_sol: bool,
+}
+
+/// Event emitted when governance allows or disallows a payment mint
+#[event]
+pub struct PaymentMintUpdatedEvent {
+    pub proposal_id: u64,
+    pub mint: Pubkey,
+    pub is_allowed: bool,
+}
+
+/// Event emitted when a data listing's terms change
+#[event]
+pub struct DataListingUpdatedEvent {
+    pub provider: Pubkey,
+    pub listing_id: String,
+    pub price_per_access: u64,
+    pub access_period: u64,
+    pub listing_version: u64,
+}
+
+/// Event emitted when a data listing is paused or reactivated
+#[event]
+pub struct DataListingStatusChangedEvent {
+    pub provider: Pubkey,
+    pub listing_id: String,
+    pub is_active: bool,
+    pub listing_version: u64,
+}
+
+/// Event emitted when a data listing is closed
+#[event]
+pub struct DataListingClosedEvent {
+    pub provider: Pubkey,
+    pub listing_id: String,
+    pub purchase_count: u64,
 }
 
 /// Event emitted when data access is purchased
@@ -145,5 +682,34 @@ pub struct DataAccessPurchasedEvent {
     pub provider: Pubkey,
     pub listing_id: String,
     pub amount_paid: u64,
+    pub platform_fee: u64,
+    pub token_holder_fee: u64,
+    pub provider_amount: u64,
+    pub expires_at: i64,
+}
+
+/// Event emitted when data access is renewed
+#[event]
+pub struct DataAccessRenewedEvent {
+    pub buyer: Pubkey,
+    pub provider: Pubkey,
+    pub listing_id: String,
+    pub amount_paid: u64,
+    pub platform_fee: u64,
+    pub token_holder_fee: u64,
+    pub provider_amount: u64,
     pub expires_at: i64,
-} 
\ No newline at end of file
+    pub renewal_count: u64,
+}
+
+/// Event emitted when the marketplace fee parameters change
+#[event]
+pub struct MarketplaceFeesUpdatedEvent {
+    pub proposal_id: u64,
+    pub previous_platform_fee_bps: u16,
+    pub previous_token_holder_fee_bps: u16,
+    pub previous_provider_fee_bps: u16,
+    pub platform_fee_bps: u16,
+    pub token_holder_fee_bps: u16,
+    pub provider_fee_bps: u16,
+}
\ No newline at end of file
@@ -1,9 +1,25 @@
 pub mod provider_operations;
 pub mod data_operations;
 pub mod marketplace_operations;
+pub mod escrow_operations;
+pub mod subscription_operations;
+pub mod bounty_operations;
+pub mod auction_operations;
+pub mod pool_operations;
+pub mod license_operations;
+pub mod access_operations;
+pub mod revocation_operations;
 pub mod data_state;
 
 pub use provider_operations::*;
 pub use data_operations::*;
 pub use marketplace_operations::*;
+pub use escrow_operations::*;
+pub use subscription_operations::*;
+pub use bounty_operations::*;
+pub use auction_operations::*;
+pub use pool_operations::*;
+pub use license_operations::*;
+pub use access_operations::*;
+pub use revocation_operations::*;
 pub use data_state::*; 
\ No newline at end of file
@@ -0,0 +1,380 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer};
+use crate::errors::BodyDfiError;
+use crate::data_marketplace::data_state::*;
+use crate::data_marketplace::marketplace_operations::{
+    calculate_fee_split, holder_fee_destination, retire_listing, set_listing_status, transfer_fee_split,
+    update_listing_terms, validate_listing_terms, DataAccessPurchasedEvent,
+};
+use crate::data_marketplace::revocation_operations::{calculate_unused_refund, DataAccessRevokedEvent};
+use crate::staking::staking_state::ACC_REWARD_PRECISION;
+
+/// Create a data pool together with its pooled listing
+pub fn create_data_pool(
+    ctx: Context<CreateDataPool>,
+    pool_id: String,
+    data_types: Vec<u8>,
+    price_per_access: u64,
+    access_period: u64,
+    description: String,
+) -> Result<()> {
+    // Validate input
+    require!(pool_id.len() <= DataListing::MAX_LISTING_ID_LEN, BodyDfiError::InputTooLong);
+    validate_listing_terms(&data_types, price_per_access, access_period, &description)?;
+    
+    let data_pool = &mut ctx.accounts.data_pool;
+    let data_listing = &mut ctx.accounts.data_listing;
+    let current_time = ctx.accounts.clock.unix_timestamp;
+    
+    // Initialize data pool
+    data_pool.curator = ctx.accounts.curator.key();
+    data_pool.pool_id = pool_id;
+    data_pool.data_types = data_types.clone();
+    data_pool.revenue_vault = ctx.accounts.revenue_vault.key();
+    data_pool.total_weight = 0;
+    data_pool.acc_revenue_per_weight = 0;
+    data_pool.member_count = 0;
+    data_pool.bump = ctx.bumps.data_pool;
+    
+    // The pooled listing is owned by the pool so it can only be bought through it
+    data_listing.provider = data_pool.key();
+    data_listing.listing_id = data_pool.pool_id.clone();
+    data_listing.data_types = data_types;
+    data_listing.price_per_access = price_per_access;
+    data_listing.access_period = access_period;
+    data_listing.description = description;
+    data_listing.purchase_count = 0;
+    data_listing.created_at = current_time;
+    data_listing.is_active = true;
+    data_listing.latest_access_expiry = 0;
+    data_listing.transferable_license = false;
+    data_listing.royalty_bps = 0;
+    data_listing.exclusive_until = 0;
+    data_listing.payment_mint = ctx.accounts.payment_mint.key();
+    data_listing.is_native_sol = false;
+    data_listing.listing_version = 0;
+    data_listing.is_closed = false;
+    
+    // Emit event
+    emit!(DataPoolCreatedEvent {
+        curator: data_pool.curator,
+        pool_id: data_pool.pool_id.clone(),
+        price_per_access,
+        access_period,
+    });
+    
+    Ok(())
+}
+
+/// Join a data pool as a provider
+pub fn join_data_pool(ctx: Context<JoinDataPool>) -> Result<()> {
+    let pool_membership = &mut ctx.accounts.pool_membership;
+    
+    // Initialize membership
+    pool_membership.data_pool = ctx.accounts.data_pool.key();
+    pool_membership.provider = ctx.accounts.provider.key();
+    pool_membership.weight = 0;
+    pool_membership.submission_count = 0;
+    pool_membership.reward_debt = 0;
+    pool_membership.pending_revenue = 0;
+    pool_membership.bump = ctx.bumps.pool_membership;
+    
+    // Emit event
+    emit!(DataPoolJoinedEvent {
+        provider: pool_membership.provider,
+        pool_id: ctx.accounts.data_pool.pool_id.clone(),
+    });
+    
+    Ok(())
+}
+
+/// Move the revenue accrued by a membership into its pending revenue
+fn settle_membership(data_pool: &DataPool, pool_membership: &mut PoolMembership) {
+    let accumulated = pool_membership.weight as u128 * data_pool.acc_revenue_per_weight;
+    let unsettled = accumulated - pool_membership.reward_debt;
+    let accrued = (unsettled / ACC_REWARD_PRECISION) as u64;
+    
+    pool_membership.pending_revenue = pool_membership.pending_revenue.checked_add(accrued).unwrap();
+    
+    // Keep the sub-token remainder for the next settlement
+    pool_membership.reward_debt = accumulated - unsettled % ACC_REWARD_PRECISION;
+}
+
+/// Commit a validated submission to a pool, weighted by its quality score plus one
+/// so that every validated submission earns a share
+pub fn commit_pool_submission(ctx: Context<CommitPoolSubmission>) -> Result<()> {
+    let data_pool = &mut ctx.accounts.data_pool;
+    let pool_membership = &mut ctx.accounts.pool_membership;
+    let data_submission = &ctx.accounts.data_submission;
+    
+    // Validate submission
+    require!(data_submission.is_validated, BodyDfiError::SubmissionNotValidated);
+    require!(
+        data_pool.data_types.contains(&data_submission.data_type),
+        BodyDfiError::InvalidDataType
+    );
+    
+    // Settle revenue accrued at the previous weight
+    settle_membership(data_pool, pool_membership);
+    
+    let weight = data_submission.quality_score as u64 + 1;
+    
+    // Update weights
+    if pool_membership.weight == 0 {
+        data_pool.member_count = data_pool.member_count.checked_add(1).unwrap();
+    }
+    pool_membership.weight = pool_membership.weight.checked_add(weight).unwrap();
+    pool_membership.submission_count = pool_membership.submission_count.checked_add(1).unwrap();
+    pool_membership.reward_debt = pool_membership.weight as u128 * data_pool.acc_revenue_per_weight;
+    data_pool.total_weight = data_pool.total_weight.checked_add(weight).unwrap();
+    
+    // Record the commitment
+    let pool_commitment = &mut ctx.accounts.pool_commitment;
+    pool_commitment.data_pool = data_pool.key();
+    pool_commitment.data_submission = data_submission.key();
+    pool_commitment.weight = weight;
+    
+    // Emit event
+    emit!(PoolSubmissionCommittedEvent {
+        provider: pool_membership.provider,
+        pool_id: data_pool.pool_id.clone(),
+        data_hash: data_submission.data_hash.clone(),
+        weight,
+        total_weight: data_pool.total_weight,
+    });
+    
+    Ok(())
+}
+
+/// Purchase or renew access to a pooled listing, crediting members pro-rata to their weight
+pub fn purchase_pool_access(
+    ctx: Context<PurchasePoolAccess>,
+    max_price: u64,
+    listing_version: u64,
+) -> Result<()> {
+    let buyer = &ctx.accounts.buyer;
+    let data_pool = &mut ctx.accounts.data_pool;
+    let data_listing = &mut ctx.accounts.data_listing;
+    let data_access = &mut ctx.accounts.data_access;
+    let current_time = ctx.accounts.clock.unix_timestamp;
+    
+    // Validate listing is active and has contributions to pay
+    require!(data_listing.is_active, BodyDfiError::InvalidDataListing);
+    require!(data_pool.total_weight > 0, BodyDfiError::EmptyDataPool);
+    
+    // Protect the buyer against terms changing between quote and confirmation
+    require!(
+        data_listing.listing_version == listing_version,
+        BodyDfiError::ListingVersionMismatch
+    );
+    require!(data_listing.price_per_access <= max_price, BodyDfiError::PriceExceedsMax);
+    
+    let is_renewal = data_access.buyer != Pubkey::default();
+    if is_renewal {
+        require!(data_access.is_valid, BodyDfiError::AccessRevoked);
+    }
+    
+    // Calculate fee splits
+    let total_amount = data_listing.price_per_access;
+    let fee_split = calculate_fee_split(total_amount, &ctx.accounts.marketplace_config)?;
+    
+    // The provider share goes to the pool's revenue vault
+    transfer_fee_split(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.buyer_token_account.to_account_info(),
+        buyer.to_account_info(),
+        ctx.accounts.revenue_vault.to_account_info(),
+        ctx.accounts.treasury_token_account.to_account_info(),
+        holder_fee_destination(&ctx.accounts.holder_rewards_vault, &ctx.accounts.treasury_token_account)?,
+        &fee_split,
+        &[],
+    )?;
+    
+    data_pool.acc_revenue_per_weight = data_pool.acc_revenue_per_weight
+        .checked_add(fee_split.provider_amount as u128 * ACC_REWARD_PRECISION / data_pool.total_weight as u128)
+        .unwrap();
+    
+    if is_renewal {
+        // Extend from the later of now and the current expiry
+        let renewed_from = current_time.max(data_access.expires_at);
+        data_access.expires_at = renewed_from + data_listing.access_period as i64;
+        data_access.amount_paid = data_access.amount_paid.checked_add(total_amount).unwrap();
+        data_access.renewal_count = data_access.renewal_count.checked_add(1).unwrap();
+    } else {
+        // Initialize data access
+        data_access.buyer = buyer.key();
+        data_access.listing_id = data_listing.listing_id.clone();
+        data_access.purchased_at = current_time;
+        data_access.expires_at = current_time + data_listing.access_period as i64;
+        data_access.amount_paid = total_amount;
+        data_access.is_valid = true;
+        data_access.renewal_count = 0;
+    }
+    data_listing.latest_access_expiry = data_listing.latest_access_expiry.max(data_access.expires_at);
+    
+    // Update listing stats
+    data_listing.purchase_count = data_listing.purchase_count.checked_add(1).unwrap();
+    
+    // Emit event
+    emit!(DataAccessPurchasedEvent {
+        buyer: buyer.key(),
+        provider: data_pool.key(),
+        listing_id: data_listing.listing_id.clone(),
+        amount_paid: total_amount,
+        platform_fee: fee_split.platform_fee,
+        token_holder_fee: fee_split.token_holder_fee,
+        provider_amount: fee_split.provider_amount,
+        expires_at: data_access.expires_at,
+    });
+    
+    Ok(())
+}
+
+/// Claim a member's share of pool revenue
+pub fn claim_pool_revenue(ctx: Context<ClaimPoolRevenue>) -> Result<()> {
+    let data_pool = &ctx.accounts.data_pool;
+    let pool_membership = &mut ctx.accounts.pool_membership;
+    
+    settle_membership(data_pool, pool_membership);
+    
+    let amount = pool_membership.pending_revenue;
+    require!(amount > 0, BodyDfiError::NoPendingRewards);
+    pool_membership.pending_revenue = 0;
+    
+    // Transfer from the revenue vault
+    let seeds = &[
+        b"data-pool".as_ref(),
+        data_pool.pool_id.as_bytes(),
+        &[data_pool.bump],
+    ];
+    let signer = &[&seeds[..]];
+    
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.revenue_vault.to_account_info(),
+        to: ctx.accounts.provider_token_account.to_account_info(),
+        authority: data_pool.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer,
+    );
+    
+    token::transfer(cpi_ctx, amount)?;
+    
+    // Emit event
+    emit!(PoolRevenueClaimedEvent {
+        provider: pool_membership.provider,
+        pool_id: data_pool.pool_id.clone(),
+        amount,
+    });
+    
+    Ok(())
+}
+
+/// Update the terms of a pooled listing and the data types the pool accepts
+pub fn update_pool_listing(
+    ctx: Context<ManagePoolListing>,
+    data_types: Vec<u8>,
+    price_per_access: u64,
+    access_period: u64,
+    description: String,
+) -> Result<()> {
+    update_listing_terms(
+        &mut ctx.accounts.data_listing,
+        data_types,
+        price_per_access,
+        access_period,
+        description,
+    )?;
+    
+    // Members can only commit submissions of the listed data types
+    ctx.accounts.data_pool.data_types = ctx.accounts.data_listing.data_types.clone();
+    
+    Ok(())
+}
+
+/// Pause or reactivate a pooled listing
+pub fn set_pool_listing_active(ctx: Context<ManagePoolListing>, is_active: bool) -> Result<()> {
+    set_listing_status(&mut ctx.accounts.data_listing, is_active);
+    
+    Ok(())
+}
+
+/// Close a pooled listing once all access granted on it has expired; members can still claim revenue
+pub fn close_pool_listing(ctx: Context<ClosePoolListing>) -> Result<()> {
+    retire_listing(&mut ctx.accounts.data_listing, ctx.accounts.clock.unix_timestamp)
+}
+
+/// Revoke a buyer's pooled access, refunding the unused fraction from the curator
+pub fn revoke_pool_access(ctx: Context<RevokePoolAccess>) -> Result<()> {
+    let data_access = &ctx.accounts.data_access;
+    let current_time = ctx.accounts.clock.unix_timestamp;
+    
+    require!(data_access.is_valid, BodyDfiError::AccessRevoked);
+    
+    // Pool revenue is already owed to members, so the curator funds the refund
+    let refund_amount = calculate_unused_refund(data_access, current_time);
+    
+    if refund_amount > 0 {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.curator_token_account.to_account_info(),
+            to: ctx.accounts.buyer_token_account.to_account_info(),
+            authority: ctx.accounts.curator.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+        
+        token::transfer(cpi_ctx, refund_amount)?;
+    }
+    
+    // Invalidate access
+    let data_access = &mut ctx.accounts.data_access;
+    data_access.is_valid = false;
+    data_access.amount_paid -= refund_amount;
+    
+    // Emit event
+    emit!(DataAccessRevokedEvent {
+        buyer: data_access.buyer,
+        provider: ctx.accounts.curator.key(),
+        listing_id: data_access.listing_id.clone(),
+        refund_amount,
+        from_escrow: false,
+        revoked_at: current_time,
+    });
+    
+    Ok(())
+}
+
+/// Event emitted when a data pool is created
+#[event]
+pub struct DataPoolCreatedEvent {
+    pub curator: Pubkey,
+    pub pool_id: String,
+    pub price_per_access: u64,
+    pub access_period: u64,
+}
+
+/// Event emitted when a provider joins a data pool
+#[event]
+pub struct DataPoolJoinedEvent {
+    pub provider: Pubkey,
+    pub pool_id: String,
+}
+
+/// Event emitted when a submission is committed to a data pool
+#[event]
+pub struct PoolSubmissionCommittedEvent {
+    pub provider: Pubkey,
+    pub pool_id: String,
+    pub data_hash: String,
+    pub weight: u64,
+    pub total_weight: u64,
+}
+
+/// Event emitted when a member claims pool revenue
+#[event]
+pub struct PoolRevenueClaimedEvent {
+    pub provider: Pubkey,
+    pub pool_id: String,
+    pub amount: u64,
+}
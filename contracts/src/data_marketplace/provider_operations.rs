@@ -26,6 +26,7 @@ pub fn register_data_provider(
     data_provider.total_rewards = 0;
     data_provider.avg_quality_score = 0;
     data_provider.reputation_score = 100; // Base reputation score
+    data_provider.last_reward_timestamp = 0;
     
     // Emit event
     emit!(ProviderRegisteredEvent {
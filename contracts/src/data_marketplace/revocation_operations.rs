@@ -0,0 +1,304 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{self, Transfer};
+use crate::errors::BodyDfiError;
+use crate::data_marketplace::data_state::*;
+
+/// Unused fraction of the amount paid, pro-rata to the time left until expiry
+pub fn calculate_unused_refund(data_access: &DataAccess, current_time: i64) -> u64 {
+    prorate_unused(
+        data_access.amount_paid,
+        data_access.purchased_at,
+        data_access.expires_at,
+        current_time,
+    )
+}
+
+/// Unused fraction of a license's primary sale price, pro-rata to the time left until expiry
+pub fn calculate_unused_license_refund(license_record: &LicenseRecord, current_time: i64) -> u64 {
+    prorate_unused(
+        license_record.amount_paid,
+        license_record.purchased_at,
+        license_record.expires_at,
+        current_time,
+    )
+}
+
+/// Share of the amount paid for the time left between now and expiry
+fn prorate_unused(amount_paid: u64, purchased_at: i64, expires_at: i64, current_time: i64) -> u64 {
+    if current_time >= expires_at {
+        return 0;
+    }
+    
+    let total_period = (expires_at - purchased_at) as u128;
+    let remaining = (expires_at - current_time.max(purchased_at)) as u128;
+    
+    (amount_paid as u128 * remaining / total_period) as u64
+}
+
+/// Split a refund into the part the escrow still holds and the part the provider
+/// already received, such as renewals paid straight to the provider
+pub fn split_escrowed_refund(refund_amount: u64, escrow_amount: u64) -> (u64, u64) {
+    let from_escrow = refund_amount.min(escrow_amount);
+    (from_escrow, refund_amount - from_escrow)
+}
+
+/// Revoke a buyer's access and refund the unused fraction of what they paid
+pub fn revoke_data_access(ctx: Context<RevokeDataAccess>) -> Result<()> {
+    let data_access = &ctx.accounts.data_access;
+    let current_time = ctx.accounts.clock.unix_timestamp;
+    
+    require!(data_access.is_valid, BodyDfiError::AccessRevoked);
+    
+    let refund_amount = calculate_unused_refund(data_access, current_time);
+    let data_access_key = data_access.key();
+    let from_escrow = ctx.accounts.purchase_escrow.is_some();
+    let mut provider_refund = refund_amount;
+    
+    // Refund from the escrow while it still holds the payment and the rest from the
+    // provider; a disputed escrow is refunded the same way and the arbiter settles
+    // what remains
+    if let Some(purchase_escrow) = ctx.accounts.purchase_escrow.as_mut() {
+        require!(
+            purchase_escrow.status == EscrowStatus::Held as u8
+                || purchase_escrow.status == EscrowStatus::Disputed as u8,
+            BodyDfiError::InvalidEscrowState
+        );
+        
+        let escrow_vault = ctx
+            .accounts
+            .escrow_vault
+            .as_ref()
+            .ok_or(BodyDfiError::InvalidTokenAccount)?;
+        let buyer_token_account = ctx
+            .accounts
+            .buyer_token_account
+            .as_ref()
+            .ok_or(BodyDfiError::InvalidTokenAccount)?;
+        require!(
+            escrow_vault.key() == purchase_escrow.vault,
+            BodyDfiError::InvalidTokenAccount
+        );
+        
+        // An escrow never holds more than the original payment; renewals were paid
+        // to the provider and are refunded from the provider below
+        let (escrow_refund, remaining_refund) = split_escrowed_refund(refund_amount, purchase_escrow.amount);
+        provider_refund = remaining_refund;
+        
+        if escrow_refund > 0 {
+            let seeds = &[
+                b"purchase-escrow".as_ref(),
+                data_access_key.as_ref(),
+                &[purchase_escrow.bump],
+            ];
+            let signer = &[&seeds[..]];
+            
+            let cpi_accounts = Transfer {
+                from: escrow_vault.to_account_info(),
+                to: buyer_token_account.to_account_info(),
+                authority: purchase_escrow.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                cpi_accounts,
+                signer,
+            );
+            
+            token::transfer(cpi_ctx, escrow_refund)?;
+        }
+        
+        // The provider can release the rest once the escrow conditions are met
+        purchase_escrow.amount -= escrow_refund;
+    }
+    
+    if provider_refund > 0 && ctx.accounts.data_listing.is_native_sol {
+        let buyer_wallet = ctx
+            .accounts
+            .buyer_wallet
+            .as_ref()
+            .ok_or(BodyDfiError::InvalidAuthority)?;
+        
+        let cpi_accounts = system_program::Transfer {
+            from: ctx.accounts.provider.to_account_info(),
+            to: buyer_wallet.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+        
+        system_program::transfer(cpi_ctx, provider_refund)?;
+    } else if provider_refund > 0 {
+        let provider_token_account = ctx
+            .accounts
+            .provider_token_account
+            .as_ref()
+            .ok_or(BodyDfiError::InvalidTokenAccount)?;
+        let buyer_token_account = ctx
+            .accounts
+            .buyer_token_account
+            .as_ref()
+            .ok_or(BodyDfiError::InvalidTokenAccount)?;
+        
+        let cpi_accounts = Transfer {
+            from: provider_token_account.to_account_info(),
+            to: buyer_token_account.to_account_info(),
+            authority: ctx.accounts.provider.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+        
+        token::transfer(cpi_ctx, provider_refund)?;
+    }
+    
+    // Invalidate access
+    let data_access = &mut ctx.accounts.data_access;
+    data_access.is_valid = false;
+    data_access.amount_paid -= refund_amount;
+    
+    // Emit event
+    emit!(DataAccessRevokedEvent {
+        buyer: data_access.buyer,
+        provider: ctx.accounts.provider.key(),
+        listing_id: data_access.listing_id.clone(),
+        refund_amount,
+        from_escrow,
+        revoked_at: current_time,
+    });
+    
+    Ok(())
+}
+
+/// Revoke a license and refund its current holder the unused fraction of the primary sale price
+pub fn revoke_license(ctx: Context<RevokeLicense>) -> Result<()> {
+    let license_record = &ctx.accounts.license_record;
+    let current_time = ctx.accounts.clock.unix_timestamp;
+    
+    require!(license_record.is_valid, BodyDfiError::InvalidLicense);
+    
+    let refund_amount = calculate_unused_license_refund(license_record, current_time);
+    
+    if refund_amount > 0 {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.provider_token_account.to_account_info(),
+            to: ctx.accounts.holder_token_account.to_account_info(),
+            authority: ctx.accounts.provider.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+        
+        token::transfer(cpi_ctx, refund_amount)?;
+    }
+    
+    // Invalidate the license; the frozen token can no longer be verified or resold
+    let license_record = &mut ctx.accounts.license_record;
+    license_record.is_valid = false;
+    license_record.amount_paid -= refund_amount;
+    
+    // Emit event
+    emit!(LicenseRevokedEvent {
+        holder: ctx.accounts.holder_license_account.owner,
+        provider: ctx.accounts.provider.key(),
+        license_mint: license_record.license_mint,
+        refund_amount,
+        revoked_at: current_time,
+    });
+    
+    Ok(())
+}
+
+/// Event emitted when a provider revokes data access
+#[event]
+pub struct DataAccessRevokedEvent {
+    pub buyer: Pubkey,
+    pub provider: Pubkey,
+    pub listing_id: String,
+    pub refund_amount: u64,
+    pub from_escrow: bool,
+    pub revoked_at: i64,
+}
+
+/// Event emitted when a provider revokes a license
+#[event]
+pub struct LicenseRevokedEvent {
+    pub holder: Pubkey,
+    pub provider: Pubkey,
+    pub license_mint: Pubkey,
+    pub refund_amount: u64,
+    pub revoked_at: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn data_access(amount_paid: u64, purchased_at: i64, expires_at: i64) -> DataAccess {
+        DataAccess {
+            buyer: Pubkey::default(),
+            listing_id: String::from("listing"),
+            purchased_at,
+            expires_at,
+            amount_paid,
+            is_valid: true,
+            renewal_count: 0,
+        }
+    }
+    
+    #[test]
+    fn full_refund_before_access_starts() {
+        let data_access = data_access(1_000, 1_000, 2_000);
+        assert_eq!(calculate_unused_refund(&data_access, 1_000), 1_000);
+        assert_eq!(calculate_unused_refund(&data_access, 0), 1_000);
+    }
+    
+    #[test]
+    fn refund_is_pro_rata_to_time_left() {
+        let data_access = data_access(1_000, 1_000, 2_000);
+        assert_eq!(calculate_unused_refund(&data_access, 1_250), 750);
+        assert_eq!(calculate_unused_refund(&data_access, 1_500), 500);
+        assert_eq!(calculate_unused_refund(&data_access, 1_999), 1);
+    }
+    
+    #[test]
+    fn no_refund_at_or_after_expiry() {
+        let data_access = data_access(1_000, 1_000, 2_000);
+        assert_eq!(calculate_unused_refund(&data_access, 2_000), 0);
+        assert_eq!(calculate_unused_refund(&data_access, i64::MAX), 0);
+    }
+    
+    #[test]
+    fn refund_rounds_down_without_overflow() {
+        let large = data_access(u64::MAX, 0, 3);
+        assert_eq!(calculate_unused_refund(&large, 1), u64::MAX / 3 * 2);
+        
+        let small = data_access(10, 0, 3);
+        assert_eq!(calculate_unused_refund(&small, 1), 6);
+    }
+    
+    #[test]
+    fn renewed_escrowed_access_refunds_renewals_from_provider() {
+        // Escrowed purchase of 1_000 for [1_000, 2_000), renewed once to 3_000
+        // with the renewal paid to the provider; the escrow still holds 1_000
+        let mut renewed = data_access(2_000, 1_000, 3_000);
+        renewed.renewal_count = 1;
+        
+        let refund_amount = calculate_unused_refund(&renewed, 1_500);
+        assert_eq!(refund_amount, 1_500);
+        assert_eq!(split_escrowed_refund(refund_amount, 1_000), (1_000, 500));
+        
+        // Late in the renewal period the escrow covers the whole refund
+        let refund_amount = calculate_unused_refund(&renewed, 2_500);
+        assert_eq!(split_escrowed_refund(refund_amount, 1_000), (500, 0));
+    }
+    
+    #[test]
+    fn license_refund_uses_primary_sale_price() {
+        let license_record = LicenseRecord {
+            listing_id: String::from("listing"),
+            license_index: 0,
+            license_mint: Pubkey::default(),
+            purchased_at: 1_000,
+            expires_at: 2_000,
+            amount_paid: 400,
+            is_valid: true,
+            bump: 0,
+        };
+        assert_eq!(calculate_unused_license_refund(&license_record, 1_750), 100);
+        assert_eq!(calculate_unused_license_refund(&license_record, 2_000), 0);
+    }
+}
@@ -0,0 +1,216 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::token::{self, Approve, Revoke};
+use crate::errors::BodyDfiError;
+use crate::data_marketplace::data_state::*;
+use crate::data_marketplace::marketplace_operations::{calculate_fee_split, holder_fee_destination, transfer_fee_split};
+
+/// Subscribe to a listing, delegating the buyer's token account to the subscription
+pub fn create_subscription(
+    ctx: Context<CreateSubscription>,
+    max_price: u64,
+    approved_amount: u64,
+) -> Result<()> {
+    let data_listing = &ctx.accounts.data_listing;
+    let data_access = &ctx.accounts.data_access;
+    
+    // Validate input
+    require!(data_listing.is_active, BodyDfiError::InvalidDataListing);
+    require!(data_access.is_valid, BodyDfiError::AccessRevoked);
+    require!(
+        max_price > 0 && approved_amount >= max_price,
+        BodyDfiError::InvalidSubscription
+    );
+    
+    // Approve the subscription PDA as delegate for future charges
+    let cpi_accounts = Approve {
+        to: ctx.accounts.buyer_token_account.to_account_info(),
+        delegate: ctx.accounts.subscription.to_account_info(),
+        authority: ctx.accounts.buyer.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    
+    token::approve(cpi_ctx, approved_amount)?;
+    
+    // Initialize subscription; the first charge is due when current access expires
+    let subscription = &mut ctx.accounts.subscription;
+    subscription.buyer = ctx.accounts.buyer.key();
+    subscription.data_access = data_access.key();
+    subscription.listing_id = data_listing.listing_id.clone();
+    subscription.buyer_token_account = ctx.accounts.buyer_token_account.key();
+    subscription.billing_period = data_listing.access_period;
+    subscription.max_price = max_price;
+    subscription.next_charge_at = data_access.expires_at;
+    subscription.charge_count = 0;
+    subscription.is_active = true;
+    subscription.bump = ctx.bumps.subscription;
+    
+    // Emit event
+    emit!(SubscriptionCreatedEvent {
+        buyer: subscription.buyer,
+        listing_id: subscription.listing_id.clone(),
+        billing_period: subscription.billing_period,
+        max_price,
+        next_charge_at: subscription.next_charge_at,
+    });
+    
+    Ok(())
+}
+
+/// Bill a due subscription and extend its data access, lapsing it if the charge cannot be made
+pub fn charge_subscription(ctx: Context<ChargeSubscription>) -> Result<()> {
+    let subscription = &ctx.accounts.subscription;
+    let data_listing = &ctx.accounts.data_listing;
+    let buyer_token_account = &ctx.accounts.buyer_token_account;
+    let current_time = ctx.accounts.clock.unix_timestamp;
+    
+    require!(subscription.is_active, BodyDfiError::InvalidSubscription);
+    
+    // Access renewed by hand since the last charge pushes the due date back
+    let due_at = subscription.next_charge_at.max(ctx.accounts.data_access.expires_at);
+    require!(current_time >= due_at, BodyDfiError::SubscriptionNotDue);
+    
+    // Lapse instead of failing when the charge cannot go through
+    let price = data_listing.price_per_access;
+    let can_charge = data_listing.is_active
+        && ctx.accounts.data_access.is_valid
+        && price <= subscription.max_price
+        && data_listing.access_period == subscription.billing_period
+        && buyer_token_account.delegate == COption::Some(subscription.key())
+        && buyer_token_account.delegated_amount >= price
+        && buyer_token_account.amount >= price;
+    
+    if !can_charge {
+        let subscription = &mut ctx.accounts.subscription;
+        subscription.is_active = false;
+    
+        // Emit event
+        emit!(SubscriptionLapsedEvent {
+            buyer: subscription.buyer,
+            listing_id: subscription.listing_id.clone(),
+            price,
+            max_price: subscription.max_price,
+        });
+    
+        return Ok(());
+    }
+    
+    // Calculate fee splits
+    let fee_split = calculate_fee_split(price, &ctx.accounts.marketplace_config)?;
+    
+    // Transfer each leg of the split as the buyer's delegate
+    let data_access_key = subscription.data_access;
+    let seeds = &[
+        b"subscription".as_ref(),
+        data_access_key.as_ref(),
+        &[subscription.bump],
+    ];
+    let signer = &[&seeds[..]];
+    
+    transfer_fee_split(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.buyer_token_account.to_account_info(),
+        ctx.accounts.subscription.to_account_info(),
+        ctx.accounts.provider_token_account.to_account_info(),
+        ctx.accounts.treasury_token_account.to_account_info(),
+        holder_fee_destination(&ctx.accounts.holder_rewards_vault, &ctx.accounts.treasury_token_account)?,
+        &fee_split,
+        signer,
+    )?;
+    
+    // Extend access from the later of now and the current expiry
+    let data_listing = &mut ctx.accounts.data_listing;
+    let data_access = &mut ctx.accounts.data_access;
+    let renewed_from = current_time.max(data_access.expires_at);
+    data_access.expires_at = renewed_from + data_listing.access_period as i64;
+    data_access.amount_paid = data_access.amount_paid.checked_add(price).unwrap();
+    data_access.renewal_count = data_access.renewal_count.checked_add(1).unwrap();
+    data_listing.latest_access_expiry = data_listing.latest_access_expiry.max(data_access.expires_at);
+    
+    let subscription = &mut ctx.accounts.subscription;
+    subscription.next_charge_at = data_access.expires_at;
+    subscription.charge_count = subscription.charge_count.checked_add(1).unwrap();
+    
+    // Update provider stats
+    let data_provider = &mut ctx.accounts.data_provider;
+    data_provider.total_rewards = data_provider.total_rewards.checked_add(fee_split.provider_amount).unwrap();
+    
+    // Emit event
+    emit!(SubscriptionChargedEvent {
+        buyer: subscription.buyer,
+        listing_id: subscription.listing_id.clone(),
+        amount_paid: price,
+        platform_fee: fee_split.platform_fee,
+        token_holder_fee: fee_split.token_holder_fee,
+        provider_amount: fee_split.provider_amount,
+        expires_at: data_access.expires_at,
+        charge_count: subscription.charge_count,
+    });
+    
+    Ok(())
+}
+
+/// Cancel a subscription, revoking its delegation and returning rent to the buyer
+pub fn cancel_subscription(ctx: Context<CancelSubscription>) -> Result<()> {
+    let subscription = &ctx.accounts.subscription;
+    
+    // Only revoke if the delegation still belongs to this subscription
+    if ctx.accounts.buyer_token_account.delegate == COption::Some(subscription.key()) {
+        let cpi_accounts = Revoke {
+            source: ctx.accounts.buyer_token_account.to_account_info(),
+            authority: ctx.accounts.buyer.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    
+        token::revoke(cpi_ctx)?;
+    }
+    
+    // Emit event
+    emit!(SubscriptionCancelledEvent {
+        buyer: subscription.buyer,
+        listing_id: subscription.listing_id.clone(),
+        charge_count: subscription.charge_count,
+    });
+    
+    Ok(())
+}
+
+/// Event emitted when a subscription is created
+#[event]
+pub struct SubscriptionCreatedEvent {
+    pub buyer: Pubkey,
+    pub listing_id: String,
+    pub billing_period: u64,
+    pub max_price: u64,
+    pub next_charge_at: i64,
+}
+
+/// Event emitted when a subscription is charged
+#[event]
+pub struct SubscriptionChargedEvent {
+    pub buyer: Pubkey,
+    pub listing_id: String,
+    pub amount_paid: u64,
+    pub platform_fee: u64,
+    pub token_holder_fee: u64,
+    pub provider_amount: u64,
+    pub expires_at: i64,
+    pub charge_count: u64,
+}
+
+/// Event emitted when a subscription lapses
+#[event]
+pub struct SubscriptionLapsedEvent {
+    pub buyer: Pubkey,
+    pub listing_id: String,
+    pub price: u64,
+    pub max_price: u64,
+}
+
+/// Event emitted when a subscription is cancelled
+#[event]
+pub struct SubscriptionCancelledEvent {
+    pub buyer: Pubkey,
+    pub listing_id: String,
+    pub charge_count: u64,
+}
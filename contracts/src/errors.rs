@@ -74,4 +74,160 @@ pub enum BodyDfiError {
     
     #[msg("Cooldown period active")]
     CooldownPeriodActive,
-} 
\ No newline at end of file
+    
+    #[msg("Invalid burn amount")]
+    InvalidBurnAmount,
+    
+    #[msg("Invalid emission schedule")]
+    InvalidEmissionSchedule,
+    
+    #[msg("Epoch emission budget exhausted")]
+    EpochBudgetExhausted,
+    
+    #[msg("Data submission not validated")]
+    SubmissionNotValidated,
+    
+    #[msg("Data submission already rewarded")]
+    SubmissionAlreadyRewarded,
+    
+    #[msg("Data submission already validated")]
+    SubmissionAlreadyValidated,
+    
+    #[msg("No pending rewards to claim")]
+    NoPendingRewards,
+    
+    #[msg("Invalid distribution")]
+    InvalidDistribution,
+    
+    #[msg("Invalid Merkle proof")]
+    InvalidMerkleProof,
+    
+    #[msg("Reward already claimed")]
+    RewardAlreadyClaimed,
+    
+    #[msg("Invalid vesting schedule")]
+    InvalidVestingSchedule,
+    
+    #[msg("No vested tokens available")]
+    NothingVested,
+    
+    #[msg("Vesting schedule is not revocable")]
+    VestingNotRevocable,
+    
+    #[msg("Program paused")]
+    ProgramPaused,
+    
+    #[msg("Invalid pause flags")]
+    InvalidPauseFlags,
+    
+    #[msg("Invalid fee parameters")]
+    InvalidFeeParameters,
+    
+    #[msg("Invalid stake amount")]
+    InvalidStakeAmount,
+    
+    #[msg("Unbonding period active")]
+    UnbondingPeriodActive,
+    
+    #[msg("Invalid escrow state")]
+    InvalidEscrowState,
+    
+    #[msg("Escrow not yet releasable")]
+    EscrowNotReleasable,
+    
+    #[msg("Dispute window closed")]
+    DisputeWindowClosed,
+    
+    #[msg("Invalid refund amount")]
+    InvalidRefundAmount,
+    
+    #[msg("Invalid dispute window")]
+    InvalidDisputeWindow,
+    
+    #[msg("Data listing has unexpired access")]
+    ListingHasActiveAccess,
+    
+    #[msg("Access revoked")]
+    AccessRevoked,
+    
+    #[msg("Invalid subscription")]
+    InvalidSubscription,
+    
+    #[msg("Subscription not due")]
+    SubscriptionNotDue,
+    
+    #[msg("Invalid bounty")]
+    InvalidBounty,
+    
+    #[msg("Bounty expired")]
+    BountyExpired,
+    
+    #[msg("Bounty not expired")]
+    BountyNotExpired,
+    
+    #[msg("Bounty already filled")]
+    BountyFilled,
+    
+    #[msg("Bounty requirements not met")]
+    BountyRequirementsNotMet,
+    
+    #[msg("Invalid auction")]
+    InvalidAuction,
+    
+    #[msg("Auction ended")]
+    AuctionEnded,
+    
+    #[msg("Auction not ended")]
+    AuctionNotEnded,
+    
+    #[msg("Auction already settled")]
+    AuctionSettled,
+    
+    #[msg("Bid too low")]
+    BidTooLow,
+    
+    #[msg("Data pool has no contributions")]
+    EmptyDataPool,
+    
+    #[msg("Listing does not issue licenses")]
+    LicenseNotEnabled,
+    
+    #[msg("Listing issues transferable licenses")]
+    LicenseRequired,
+    
+    #[msg("Invalid license")]
+    InvalidLicense,
+    
+    #[msg("Invalid royalty")]
+    InvalidRoyalty,
+    
+    #[msg("Payment mint not allowed")]
+    InvalidPaymentMint,
+    
+    #[msg("Listing price exceeds the maximum price")]
+    PriceExceedsMax,
+    
+    #[msg("Listing changed since it was quoted")]
+    ListingVersionMismatch,
+    
+    #[msg("Withdraw unbonded tokens before unstaking again")]
+    UnbondingPending,
+    
+    #[msg("Input exceeds the space reserved for it")]
+    InputTooLong,
+    
+    #[msg("Data listing has been closed")]
+    ListingClosed,
+    
+    #[msg("Auction can still be settled")]
+    AuctionSettlementOpen,
+    
+    #[msg("Not available for SOL-priced listings")]
+    NativeSolNotSupported,
+    
+    #[msg("Governance mint already set")]
+    GovernanceMintAlreadySet,
+    
+    #[msg("No holder rewards vault for the payment currency")]
+    HolderRewardsVaultMismatch,
+}
\ No newline at end of file
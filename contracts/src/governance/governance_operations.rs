@@ -147,6 +147,69 @@ pub fn execute_proposal(proposal: &mut Account<Proposal>) -> Result<()> {
     Ok(())
 }
 
+/// Attach the parameter change a parameter change proposal will apply;
+/// only possible before the first vote so voters always see the payload
+pub fn attach_parameter_change(
+    ctx: Context<AttachParameterChange>,
+    kind: u8,
+    target: Pubkey,
+    values: [u64; 4],
+) -> Result<()> {
+    let proposal = &ctx.accounts.proposal;
+    require!(
+        proposal.yes_votes == 0 && proposal.no_votes == 0,
+        BodyDfiError::InvalidProposal
+    );
+    require!(
+        ctx.accounts.clock.unix_timestamp < proposal.voting_end_time,
+        BodyDfiError::InvalidProposal
+    );
+    
+    let parameter_change = &mut ctx.accounts.parameter_change;
+    
+    parameter_change.proposal_id = ctx.accounts.proposal.id;
+    parameter_change.kind = kind;
+    parameter_change.target = target;
+    parameter_change.values = values;
+    
+    // Emit event
+    emit!(ParameterChangeAttachedEvent {
+        proposal_id: parameter_change.proposal_id,
+        kind,
+        target,
+        values,
+    });
+    
+    Ok(())
+}
+
+/// Execute a passed parameter change proposal of the expected kind,
+/// finalizing it first if its voting period has ended
+pub fn execute_parameter_change(
+    proposal: &mut Account<Proposal>,
+    parameter_change: &Account<ParameterChange>,
+    kind: u8,
+) -> Result<()> {
+    require!(
+        proposal.proposal_type == PROPOSAL_TYPE_PARAMETER_CHANGE,
+        BodyDfiError::InvalidProposal
+    );
+    require!(
+        parameter_change.proposal_id == proposal.id && parameter_change.kind == kind,
+        BodyDfiError::InvalidProposal
+    );
+    
+    if proposal.status == ProposalStatus::Active as u8 {
+        require!(
+            Clock::get()?.unix_timestamp > proposal.voting_end_time,
+            BodyDfiError::InvalidProposal
+        );
+        finalize_proposal(proposal)?;
+    }
+    
+    execute_proposal(proposal)
+}
+
 /// Event emitted when a new proposal is created
 #[event]
 pub struct ProposalCreatedEvent {
@@ -180,4 +243,13 @@ pub struct ProposalFinalizedEvent {
 pub struct ProposalExecutedEvent {
     pub proposal_id: u64,
     pub executed_at: i64,
-} 
\ No newline at end of file
+}
+
+/// Event emitted when a parameter change is attached to a proposal
+#[event]
+pub struct ParameterChangeAttachedEvent {
+    pub proposal_id: u64,
+    pub kind: u8,
+    pub target: Pubkey,
+    pub values: [u64; 4],
+}
\ No newline at end of file
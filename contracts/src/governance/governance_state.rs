@@ -7,15 +7,36 @@ pub const PROPOSAL_TYPE_FUND_ALLOCATION: u8 = 1;
 pub const PROPOSAL_TYPE_DATA_STANDARDS: u8 = 2;
 pub const PROPOSAL_TYPE_NEW_FEATURE: u8 = 3;
 
+/// Parameter change kinds
+pub const PARAMETER_KIND_UNPAUSE: u8 = 0;
+pub const PARAMETER_KIND_MARKETPLACE_FEES: u8 = 1;
+pub const PARAMETER_KIND_PAYMENT_MINT: u8 = 2;
+
 /// Create proposal account context
 #[derive(Accounts)]
 pub struct CreateProposal<'info> {
     #[account(mut)]
     pub proposer: Signer<'info>,
     
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_GOVERNANCE) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    /// Program record of the BodyDFi governance token
+    #[account(
+        seeds = [b"token-mint", program_config.governance_mint.as_ref()],
+        bump,
+        constraint = !governance_token_mint.is_move_token @ BodyDfiError::InvalidMint
+    )]
+    pub governance_token_mint: Account<'info, TokenMint>,
+    
     #[account(
         mut,
         constraint = proposer_token_account.owner == proposer.key() @ BodyDfiError::InvalidAuthority,
+        constraint = proposer_token_account.mint == governance_token_mint.mint @ BodyDfiError::InvalidMint,
         constraint = proposer_token_account.amount >= MIN_TOKENS_TO_PROPOSE @ BodyDfiError::InsufficientVotingBalance
     )]
     pub proposer_token_account: Account<'info, TokenAccount>,
@@ -35,10 +56,18 @@ pub struct CreateProposal<'info> {
 
 /// Cast vote account context
 #[derive(Accounts)]
+#[instruction(proposal_id: u64)]
 pub struct CastVote<'info> {
     #[account(mut)]
     pub voter: Signer<'info>,
     
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_GOVERNANCE) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
     #[account(
         mut,
         seeds = [b"proposal", &proposal_id.to_le_bytes()],
@@ -47,9 +76,18 @@ pub struct CastVote<'info> {
     )]
     pub proposal: Account<'info, Proposal>,
     
+    /// Program record of the BodyDFi governance token
+    #[account(
+        seeds = [b"token-mint", program_config.governance_mint.as_ref()],
+        bump,
+        constraint = !governance_token_mint.is_move_token @ BodyDfiError::InvalidMint
+    )]
+    pub governance_token_mint: Account<'info, TokenMint>,
+    
     #[account(
         mut,
         constraint = voter_token_account.owner == voter.key() @ BodyDfiError::InvalidAuthority,
+        constraint = voter_token_account.mint == governance_token_mint.mint @ BodyDfiError::InvalidMint,
         constraint = voter_token_account.amount > 0 @ BodyDfiError::InsufficientVotingBalance
     )]
     pub voter_token_account: Account<'info, TokenAccount>,
@@ -67,6 +105,41 @@ pub struct CastVote<'info> {
     pub clock: Sysvar<'info, Clock>,
 }
 
+/// Attach parameter change account context
+#[derive(Accounts)]
+pub struct AttachParameterChange<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    
+    #[account(
+        seeds = [b"proposal", &proposal.id.to_le_bytes()],
+        bump,
+        constraint = proposal.proposer == proposer.key() @ BodyDfiError::InvalidAuthority,
+        constraint = proposal.status == ProposalStatus::Active as u8 @ BodyDfiError::InvalidProposal,
+        constraint = proposal.proposal_type == PROPOSAL_TYPE_PARAMETER_CHANGE @ BodyDfiError::InvalidProposal
+    )]
+    pub proposal: Account<'info, Proposal>,
+    
+    #[account(
+        init,
+        payer = proposer,
+        seeds = [b"parameter-change".as_ref(), &proposal.id.to_le_bytes()],
+        bump,
+        space = 8 + ParameterChange::LEN
+    )]
+    pub parameter_change: Account<'info, ParameterChange>,
+    
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_GOVERNANCE) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
 /// Proposal account
 #[account]
 pub struct Proposal {
@@ -108,6 +181,26 @@ impl Proposal {
     pub const LEN: usize = 8 + 32 + 100 + 1000 + 1 + 8 + 8 + 8 + 8 + 1 + 1;
 }
 
+/// Parameter change applied when a parameter change proposal is executed
+#[account]
+pub struct ParameterChange {
+    /// Proposal this change belongs to
+    pub proposal_id: u64,
+    
+    /// Kind of parameter being changed
+    pub kind: u8,
+    
+    /// Account the change applies to, if any
+    pub target: Pubkey,
+    
+    /// New parameter values, interpreted according to the kind
+    pub values: [u64; 4],
+}
+
+impl ParameterChange {
+    pub const LEN: usize = 8 + 1 + 32 + 8 * 4;
+}
+
 /// Vote account
 #[account]
 pub struct Vote {
@@ -151,4 +244,6 @@ pub fn next_proposal_id() -> u64 {
 }
 
 // Import error code
-use crate::errors::BodyDfiError; 
\ No newline at end of file
+use crate::errors::BodyDfiError;
+use crate::config::config_state::{ProgramConfig, PAUSE_GOVERNANCE};
+use crate::token::token_state::TokenMint; 
\ No newline at end of file
@@ -1,14 +1,16 @@
+// The #[program] module re-exports instruction handlers with the same names
+// as the operation modules below.
+#![allow(ambiguous_glob_reexports)]
+
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, Token, TokenAccount};
-use solana_program::{
-    program_error::ProgramError,
-    pubkey::Pubkey,
-};
+use solana_program::pubkey::Pubkey;
 
 // Import project modules
 pub mod token;
 pub mod data_marketplace;
 pub mod governance;
+pub mod config;
+pub mod staking;
 pub mod errors;
 
 // Re-export key components
@@ -16,6 +18,8 @@ pub use errors::*;
 pub use token::*;
 pub use data_marketplace::*;
 pub use governance::*;
+pub use config::*;
+pub use staking::*;
 
 declare_id!("BDFiC3XMQn4DCf3gFJJG9oKxVTXWE79MHBd6rZCvw2xk");
 
@@ -24,6 +28,73 @@ declare_id!("BDFiC3XMQn4DCf3gFJJG9oKxVTXWE79MHBd6rZCvw2xk");
 pub mod bodydfi {
     use super::*;
     
+    /// Initialize the program-wide configuration
+    pub fn initialize_program_config(
+        ctx: Context<InitializeProgramConfig>,
+        treasury: Pubkey,
+        holder_rewards_vault: Pubkey,
+        feature_flags: u64,
+    ) -> Result<()> {
+        config::config_operations::initialize_program_config(ctx, treasury, holder_rewards_vault, feature_flags)
+    }
+    
+    /// Update treasury addresses and feature flags
+    pub fn update_program_config(
+        ctx: Context<UpdateProgramConfig>,
+        treasury: Pubkey,
+        holder_rewards_vault: Pubkey,
+        feature_flags: u64,
+    ) -> Result<()> {
+        config::config_operations::update_program_config(ctx, treasury, holder_rewards_vault, feature_flags)
+    }
+    
+    /// Propose a new program admin
+    pub fn propose_admin_transfer(
+        ctx: Context<UpdateProgramConfig>,
+        new_admin: Pubkey,
+    ) -> Result<()> {
+        config::config_operations::propose_admin_transfer(ctx, new_admin)
+    }
+    
+    /// Accept the program admin role
+    pub fn accept_admin_transfer(ctx: Context<AcceptAdminTransfer>) -> Result<()> {
+        config::config_operations::accept_admin_transfer(ctx)
+    }
+    
+    /// Set the guardian allowed to pause the program
+    pub fn set_guardian(
+        ctx: Context<UpdateProgramConfig>,
+        guardian: Pubkey,
+    ) -> Result<()> {
+        config::config_operations::set_guardian(ctx, guardian)
+    }
+    
+    /// Set the BodyDFi mint that carries voting power (once)
+    pub fn set_governance_mint(ctx: Context<SetGovernanceMint>) -> Result<()> {
+        config::config_operations::set_governance_mint(ctx)
+    }
+    
+    /// Pause subsystems (guardian or admin)
+    pub fn pause(
+        ctx: Context<Pause>,
+        subsystems: u8,
+    ) -> Result<()> {
+        config::config_operations::pause(ctx, subsystems)
+    }
+    
+    /// Unpause subsystems (admin)
+    pub fn unpause(
+        ctx: Context<UpdateProgramConfig>,
+        subsystems: u8,
+    ) -> Result<()> {
+        config::config_operations::unpause(ctx, subsystems)
+    }
+    
+    /// Unpause subsystems through a passed governance proposal
+    pub fn execute_unpause(ctx: Context<ExecuteUnpause>) -> Result<()> {
+        config::config_operations::execute_unpause(ctx)
+    }
+    
     /// Initialize the MOVE token (utility token)
     pub fn initialize_move_token(
         ctx: Context<InitializeToken>,
@@ -37,7 +108,7 @@ pub mod bodydfi {
     
     /// Initialize the BodyDFi token (governance token)
     pub fn initialize_bodydfi_token(
-        ctx: Context<InitializeToken>,
+        ctx: Context<InitializeBodyDfiToken>,
         name: String,
         symbol: String,
         uri: String,
@@ -47,6 +118,47 @@ pub mod bodydfi {
         token::token_operations::initialize_bodydfi_token(ctx, name, symbol, uri, decimals, total_supply)
     }
     
+    /// Propose a new authority for a token
+    pub fn propose_authority_transfer(
+        ctx: Context<ProposeAuthorityTransfer>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        token::token_operations::propose_authority_transfer(ctx, new_authority)
+    }
+    
+    /// Accept the authority role of a token
+    pub fn accept_authority_transfer(ctx: Context<AcceptAuthorityTransfer>) -> Result<()> {
+        token::token_operations::accept_authority_transfer(ctx)
+    }
+    
+    /// Create a vesting schedule from the BodyDFi token allocation
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_vesting(
+        ctx: Context<CreateVesting>,
+        vesting_id: u64,
+        beneficiary: Pubkey,
+        category: u8,
+        amount: u64,
+        start_timestamp: i64,
+        cliff_duration: i64,
+        vesting_duration: i64,
+        revocable: bool,
+    ) -> Result<()> {
+        token::vesting_operations::create_vesting(
+            ctx, vesting_id, beneficiary, category, amount, start_timestamp, cliff_duration, vesting_duration, revocable
+        )
+    }
+    
+    /// Withdraw vested BodyDFi tokens
+    pub fn withdraw_vested(ctx: Context<WithdrawVested>) -> Result<()> {
+        token::vesting_operations::withdraw_vested(ctx)
+    }
+    
+    /// Revoke the unvested part of a vesting schedule
+    pub fn revoke_vesting(ctx: Context<RevokeVesting>) -> Result<()> {
+        token::vesting_operations::revoke_vesting(ctx)
+    }
+    
     /// Register a new data provider (user with wearable device)
     pub fn register_data_provider(
         ctx: Context<RegisterDataProvider>,
@@ -67,6 +179,16 @@ pub mod bodydfi {
         data_marketplace::data_operations::submit_data(ctx, data_hash, data_type, timestamp, metadata)
     }
     
+    /// Validate a data submission (trusted validator only)
+    pub fn validate_data(ctx: Context<ValidateData>, quality_score: u8) -> Result<()> {
+        let accounts = ctx.accounts;
+        data_marketplace::data_operations::validate_data(
+            &mut accounts.data_submission,
+            &mut accounts.data_provider,
+            quality_score,
+        )
+    }
+    
     /// Create a data marketplace listing
     pub fn create_data_listing(
         ctx: Context<CreateDataListing>,
@@ -81,12 +203,300 @@ pub mod bodydfi {
         )
     }
     
-    /// Purchase data access
+    /// Purchase data access at no more than `max_price` and only at the quoted `listing_version`
     pub fn purchase_data_access(
         ctx: Context<PurchaseDataAccess>,
-        listing_id: String,
+        max_price: u64,
+        listing_version: u64,
+    ) -> Result<()> {
+        data_marketplace::marketplace_operations::purchase_data_access(
+            ctx, max_price, listing_version
+        )
+    }
+    
+    /// Renew data access at no more than `max_price` and only at the quoted `listing_version`
+    pub fn renew_data_access(
+        ctx: Context<RenewDataAccess>,
+        max_price: u64,
+        listing_version: u64,
+    ) -> Result<()> {
+        data_marketplace::marketplace_operations::renew_data_access(ctx, max_price, listing_version)
+    }
+    
+    /// Verify data access and return its details
+    pub fn verify_data_access(ctx: Context<VerifyDataAccess>) -> Result<AccessInfo> {
+        data_marketplace::access_operations::verify_data_access(ctx)
+    }
+    
+    /// Verify access held through a license token and return its details
+    pub fn verify_license_access(ctx: Context<VerifyLicenseAccess>) -> Result<AccessInfo> {
+        data_marketplace::access_operations::verify_license_access(ctx)
+    }
+    
+    /// Revoke a buyer's data access with a pro-rata refund
+    pub fn revoke_data_access(ctx: Context<RevokeDataAccess>) -> Result<()> {
+        data_marketplace::revocation_operations::revoke_data_access(ctx)
+    }
+    
+    /// Revoke a license with a pro-rata refund to its holder
+    pub fn revoke_license(ctx: Context<RevokeLicense>) -> Result<()> {
+        data_marketplace::revocation_operations::revoke_license(ctx)
+    }
+    
+    /// Subscribe to recurring billing of a data listing
+    pub fn create_subscription(
+        ctx: Context<CreateSubscription>,
+        max_price: u64,
+        approved_amount: u64,
+    ) -> Result<()> {
+        data_marketplace::subscription_operations::create_subscription(ctx, max_price, approved_amount)
+    }
+    
+    /// Charge a due subscription and extend its data access
+    pub fn charge_subscription(ctx: Context<ChargeSubscription>) -> Result<()> {
+        data_marketplace::subscription_operations::charge_subscription(ctx)
+    }
+    
+    /// Cancel a subscription
+    pub fn cancel_subscription(ctx: Context<CancelSubscription>) -> Result<()> {
+        data_marketplace::subscription_operations::cancel_subscription(ctx)
+    }
+    
+    /// Post a data bounty with escrowed funds
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_bounty(
+        ctx: Context<CreateBounty>,
+        bounty_id: String,
+        data_types: Vec<u8>,
+        device_types: Vec<u8>,
+        min_reputation_score: u16,
+        price_per_provider: u64,
+        max_providers: u64,
+        access_period: u64,
+        duration: i64,
+    ) -> Result<()> {
+        data_marketplace::bounty_operations::create_bounty(
+            ctx, bounty_id, data_types, device_types, min_reputation_score,
+            price_per_provider, max_providers, access_period, duration
+        )
+    }
+    
+    /// Accept a data bounty with one of the provider's listings
+    pub fn accept_bounty(ctx: Context<AcceptBounty>) -> Result<()> {
+        data_marketplace::bounty_operations::accept_bounty(ctx)
+    }
+    
+    /// Reclaim unspent escrow from an expired bounty
+    pub fn reclaim_bounty(ctx: Context<ReclaimBounty>) -> Result<()> {
+        data_marketplace::bounty_operations::reclaim_bounty(ctx)
+    }
+    
+    /// Create an English or Dutch auction for a data listing
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_auction(
+        ctx: Context<CreateAuction>,
+        auction_id: u64,
+        kind: u8,
+        start_price: u64,
+        end_price: u64,
+        min_increment: u64,
+        duration: i64,
+        extension_window: i64,
     ) -> Result<()> {
-        data_marketplace::marketplace_operations::purchase_data_access(ctx, listing_id)
+        data_marketplace::auction_operations::create_auction(
+            ctx, auction_id, kind, start_price, end_price, min_increment, duration, extension_window
+        )
+    }
+    
+    /// Place a bid on an English auction
+    pub fn place_bid(ctx: Context<PlaceBid>, amount: u64) -> Result<()> {
+        data_marketplace::auction_operations::place_bid(ctx, amount)
+    }
+    
+    /// Settle an ended English auction
+    pub fn settle_auction(ctx: Context<SettleAuction>) -> Result<()> {
+        data_marketplace::auction_operations::settle_auction(ctx)
+    }
+    
+    /// Buy a Dutch auction at its current price
+    pub fn buy_dutch_auction(ctx: Context<BuyDutchAuction>) -> Result<()> {
+        data_marketplace::auction_operations::buy_dutch_auction(ctx)
+    }
+    
+    /// Cancel an auction without bids
+    pub fn cancel_auction(ctx: Context<CancelAuction>) -> Result<()> {
+        data_marketplace::auction_operations::cancel_auction(ctx)
+    }
+    
+    /// Refund the winning bid of an English auction left unsettled
+    pub fn refund_auction_bid(ctx: Context<RefundAuctionBid>) -> Result<()> {
+        data_marketplace::auction_operations::refund_auction_bid(ctx)
+    }
+    
+    /// Create a cooperative data pool and its pooled listing
+    pub fn create_data_pool(
+        ctx: Context<CreateDataPool>,
+        pool_id: String,
+        data_types: Vec<u8>,
+        price_per_access: u64,
+        access_period: u64,
+        description: String,
+    ) -> Result<()> {
+        data_marketplace::pool_operations::create_data_pool(
+            ctx, pool_id, data_types, price_per_access, access_period, description
+        )
+    }
+    
+    /// Join a data pool
+    pub fn join_data_pool(ctx: Context<JoinDataPool>) -> Result<()> {
+        data_marketplace::pool_operations::join_data_pool(ctx)
+    }
+    
+    /// Commit a validated submission to a data pool
+    pub fn commit_pool_submission(ctx: Context<CommitPoolSubmission>) -> Result<()> {
+        data_marketplace::pool_operations::commit_pool_submission(ctx)
+    }
+    
+    /// Purchase or renew access to a pooled listing at no more than `max_price` and only at the quoted `listing_version`
+    pub fn purchase_pool_access(
+        ctx: Context<PurchasePoolAccess>,
+        max_price: u64,
+        listing_version: u64,
+    ) -> Result<()> {
+        data_marketplace::pool_operations::purchase_pool_access(ctx, max_price, listing_version)
+    }
+    
+    /// Update the terms of a pooled listing
+    pub fn update_pool_listing(
+        ctx: Context<ManagePoolListing>,
+        data_types: Vec<u8>,
+        price_per_access: u64,
+        access_period: u64,
+        description: String,
+    ) -> Result<()> {
+        data_marketplace::pool_operations::update_pool_listing(
+            ctx, data_types, price_per_access, access_period, description
+        )
+    }
+    
+    /// Pause or reactivate a pooled listing
+    pub fn set_pool_listing_active(ctx: Context<ManagePoolListing>, is_active: bool) -> Result<()> {
+        data_marketplace::pool_operations::set_pool_listing_active(ctx, is_active)
+    }
+    
+    /// Permanently close a pooled listing, returning its rent except for a tombstone that keeps the ID reserved
+    pub fn close_pool_listing(ctx: Context<ClosePoolListing>) -> Result<()> {
+        data_marketplace::pool_operations::close_pool_listing(ctx)
+    }
+    
+    /// Revoke a buyer's pooled access with a pro-rata refund
+    pub fn revoke_pool_access(ctx: Context<RevokePoolAccess>) -> Result<()> {
+        data_marketplace::pool_operations::revoke_pool_access(ctx)
+    }
+    
+    /// Claim a member's share of pool revenue
+    pub fn claim_pool_revenue(ctx: Context<ClaimPoolRevenue>) -> Result<()> {
+        data_marketplace::pool_operations::claim_pool_revenue(ctx)
+    }
+    
+    /// Enable transferable licenses on a listing and set the resale royalty
+    pub fn set_license_options(
+        ctx: Context<ManageDataListing>,
+        transferable_license: bool,
+        royalty_bps: u16,
+    ) -> Result<()> {
+        data_marketplace::license_operations::set_license_options(ctx, transferable_license, royalty_bps)
+    }
+    
+    /// Purchase a license token at no more than `max_price` and only at the quoted `listing_version`
+    pub fn purchase_license(
+        ctx: Context<PurchaseLicense>,
+        max_price: u64,
+        listing_version: u64,
+    ) -> Result<()> {
+        data_marketplace::license_operations::purchase_license(ctx, max_price, listing_version)
+    }
+    
+    /// Resell a license token with the provider royalty
+    pub fn resell_license(ctx: Context<ResellLicense>, price: u64) -> Result<()> {
+        data_marketplace::license_operations::resell_license(ctx, price)
+    }
+    
+    /// Update data listing terms
+    pub fn update_data_listing(
+        ctx: Context<ManageDataListing>,
+        data_types: Vec<u8>,
+        price_per_access: u64,
+        access_period: u64,
+        description: String,
+    ) -> Result<()> {
+        data_marketplace::marketplace_operations::update_data_listing(
+            ctx, data_types, price_per_access, access_period, description
+        )
+    }
+    
+    /// Pause or reactivate a data listing
+    pub fn set_listing_active(ctx: Context<ManageDataListing>, is_active: bool) -> Result<()> {
+        data_marketplace::marketplace_operations::set_listing_active(ctx, is_active)
+    }
+    
+    /// Permanently close a data listing, returning its rent except for a tombstone that keeps the ID reserved
+    pub fn close_data_listing(ctx: Context<CloseDataListing>) -> Result<()> {
+        data_marketplace::marketplace_operations::close_data_listing(ctx)
+    }
+    
+    /// Initialize the marketplace config with the default fee split
+    pub fn initialize_marketplace_config(ctx: Context<InitializeMarketplaceConfig>) -> Result<()> {
+        data_marketplace::marketplace_operations::initialize_marketplace_config(ctx)
+    }
+    
+    /// Apply marketplace fee parameters from a passed governance proposal
+    pub fn execute_fee_parameter_change(ctx: Context<ExecuteFeeParameterChange>) -> Result<()> {
+        data_marketplace::marketplace_operations::execute_fee_parameter_change(ctx)
+    }
+    
+    /// Apply a passed payment mint allowlist change
+    pub fn execute_payment_mint_change(ctx: Context<ExecutePaymentMintChange>) -> Result<()> {
+        data_marketplace::marketplace_operations::execute_payment_mint_change(ctx)
+    }
+    
+    /// Update the escrow arbiter and dispute window
+    pub fn update_escrow_settings(
+        ctx: Context<UpdateEscrowSettings>,
+        arbiter: Pubkey,
+        dispute_window: i64,
+    ) -> Result<()> {
+        data_marketplace::escrow_operations::update_escrow_settings(ctx, arbiter, dispute_window)
+    }
+    
+    /// Purchase data access with the payment held in escrow, at no more than `max_price`
+    /// and only at the quoted `listing_version`
+    pub fn purchase_data_access_escrowed(
+        ctx: Context<PurchaseDataAccessEscrowed>,
+        max_price: u64,
+        listing_version: u64,
+    ) -> Result<()> {
+        data_marketplace::escrow_operations::purchase_data_access_escrowed(ctx, max_price, listing_version)
+    }
+    
+    /// Confirm delivery of an escrowed purchase
+    pub fn confirm_delivery(ctx: Context<EscrowBuyerAction>) -> Result<()> {
+        data_marketplace::escrow_operations::confirm_delivery(ctx)
+    }
+    
+    /// Open a dispute on an escrowed purchase
+    pub fn open_dispute(ctx: Context<EscrowBuyerAction>) -> Result<()> {
+        data_marketplace::escrow_operations::open_dispute(ctx)
+    }
+    
+    /// Release an escrowed payment to the provider
+    pub fn release_escrow(ctx: Context<ReleaseEscrow>) -> Result<()> {
+        data_marketplace::escrow_operations::release_escrow(ctx)
+    }
+    
+    /// Resolve a disputed escrowed purchase
+    pub fn resolve_dispute(ctx: Context<ResolveDispute>, refund_amount: u64) -> Result<()> {
+        data_marketplace::escrow_operations::resolve_dispute(ctx, refund_amount)
     }
     
     /// Create governance proposal
@@ -109,13 +519,116 @@ pub mod bodydfi {
         governance::governance_operations::cast_vote(ctx, proposal_id, vote)
     }
     
-    /// Reward data provider with MOVE tokens
+    /// Initialize the MOVE emission schedule
+    pub fn initialize_emission_schedule(
+        ctx: Context<InitializeEmissionSchedule>,
+        epoch_length: i64,
+        initial_epoch_budget: u64,
+        decay_bps: u16,
+        decay_interval: u64,
+    ) -> Result<()> {
+        token::reward_operations::initialize_emission_schedule(
+            ctx, epoch_length, initial_epoch_budget, decay_bps, decay_interval
+        )
+    }
+    
+    /// Attach a parameter change to a parameter change proposal
+    pub fn attach_parameter_change(
+        ctx: Context<AttachParameterChange>,
+        kind: u8,
+        target: Pubkey,
+        values: [u64; 4],
+    ) -> Result<()> {
+        governance::governance_operations::attach_parameter_change(ctx, kind, target, values)
+    }
+    
+    /// Reward data provider with MOVE tokens for a validated submission
     pub fn reward_data_provider(
         ctx: Context<RewardDataProvider>,
-        provider: Pubkey,
         amount: u64,
-        data_quality_score: u8,
     ) -> Result<()> {
-        token::reward_operations::reward_data_provider(ctx, provider, amount, data_quality_score)
+        token::reward_operations::reward_data_provider(ctx, amount)
+    }
+    
+    /// Accrue MOVE rewards for a validated submission into pending rewards
+    pub fn accrue_reward(
+        ctx: Context<AccrueReward>,
+        amount: u64,
+    ) -> Result<()> {
+        token::reward_operations::accrue_reward(ctx, amount)
+    }
+    
+    /// Claim pending MOVE rewards
+    pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
+        token::reward_operations::claim_rewards(ctx)
+    }
+    
+    /// Publish a Merkle root of epoch reward payouts
+    pub fn publish_distribution(
+        ctx: Context<PublishDistribution>,
+        epoch: u64,
+        merkle_root: [u8; 32],
+        total_amount: u64,
+        num_leaves: u32,
+    ) -> Result<()> {
+        token::distributor_operations::publish_distribution(ctx, epoch, merkle_root, total_amount, num_leaves)
+    }
+    
+    /// Claim an epoch reward payout with a Merkle proof
+    pub fn claim_with_proof(
+        ctx: Context<ClaimWithProof>,
+        epoch: u64,
+        index: u32,
+        amount: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        token::distributor_operations::claim_with_proof(ctx, epoch, index, amount, proof)
+    }
+    
+    /// Burn tokens and reduce the tracked circulating supply
+    pub fn burn_tokens(
+        ctx: Context<BurnTokens>,
+        amount: u64,
+    ) -> Result<()> {
+        token::token_operations::burn_tokens(ctx, amount)
+    }
+    
+    /// Compare tracked supply against the SPL mint supply
+    pub fn reconcile_supply(ctx: Context<ReconcileSupply>) -> Result<()> {
+        token::token_operations::reconcile_supply(ctx)
+    }
+    
+    /// Initialize the BodyDFi staking pool
+    pub fn initialize_staking_pool(
+        ctx: Context<InitializeStakingPool>,
+        unbonding_period: i64,
+    ) -> Result<()> {
+        staking::staking_operations::initialize_staking_pool(ctx, unbonding_period)
+    }
+    
+    /// Stake BodyDFi tokens
+    pub fn stake(
+        ctx: Context<Stake>,
+        amount: u64,
+    ) -> Result<()> {
+        staking::staking_operations::stake(ctx, amount)
+    }
+    
+    /// Unstake BodyDFi tokens, starting the unbonding period
+    pub fn unstake(
+        ctx: Context<Unstake>,
+        amount: u64,
+    ) -> Result<()> {
+        staking::staking_operations::unstake(ctx, amount)
+    }
+    
+    /// Withdraw unstaked tokens after the unbonding period
+    pub fn withdraw_unstaked(ctx: Context<WithdrawUnstaked>) -> Result<()> {
+        staking::staking_operations::withdraw_unstaked(ctx)
+    }
+    
+    /// Claim the accrued share of marketplace fees
+    pub fn claim_fee_share(ctx: Context<ClaimFeeShare>) -> Result<()> {
+        staking::staking_operations::claim_fee_share(ctx)
     }
-} 
\ No newline at end of file
+}
\ No newline at end of file
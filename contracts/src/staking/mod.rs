@@ -0,0 +1,5 @@
+pub mod staking_operations;
+pub mod staking_state;
+
+pub use staking_operations::*;
+pub use staking_state::*;
\ No newline at end of file
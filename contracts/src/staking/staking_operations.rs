@@ -0,0 +1,273 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer};
+use crate::errors::BodyDfiError;
+use crate::staking::staking_state::*;
+
+/// Initialize the staking pool and route the token holder fee share to it
+pub fn initialize_staking_pool(
+    ctx: Context<InitializeStakingPool>,
+    unbonding_period: i64,
+) -> Result<()> {
+    require!(unbonding_period >= 0, BodyDfiError::InvalidStakeAmount);
+    
+    let staking_pool = &mut ctx.accounts.staking_pool;
+    
+    // Initialize staking pool
+    staking_pool.stake_mint = ctx.accounts.stake_mint.key();
+    staking_pool.reward_mint = ctx.accounts.reward_mint.key();
+    staking_pool.stake_vault = ctx.accounts.stake_vault.key();
+    staking_pool.fee_vault = ctx.accounts.fee_vault.key();
+    staking_pool.total_staked = 0;
+    staking_pool.acc_reward_per_share = 0;
+    staking_pool.accounted_fee_balance = 0;
+    staking_pool.unbonding_period = unbonding_period;
+    staking_pool.bump = ctx.bumps.staking_pool;
+    
+    // Marketplace purchases now pay the token holder share into the pool
+    ctx.accounts.program_config.holder_rewards_vault = staking_pool.fee_vault;
+    
+    // Emit event
+    emit!(StakingPoolInitializedEvent {
+        stake_mint: staking_pool.stake_mint,
+        reward_mint: staking_pool.reward_mint,
+        fee_vault: staking_pool.fee_vault,
+        unbonding_period,
+    });
+    
+    Ok(())
+}
+
+/// Fold fees received by the fee vault since the last sync into the
+/// reward-per-share accumulator
+pub fn sync_pool(staking_pool: &mut Account<StakingPool>, fee_vault_balance: u64) {
+    // Fees received while nothing is staked go to the next stakers
+    if staking_pool.total_staked == 0 {
+        return;
+    }
+    
+    let new_fees = fee_vault_balance.saturating_sub(staking_pool.accounted_fee_balance);
+    if new_fees == 0 {
+        return;
+    }
+    
+    staking_pool.acc_reward_per_share = staking_pool.acc_reward_per_share
+        .checked_add(new_fees as u128 * ACC_REWARD_PRECISION / staking_pool.total_staked as u128)
+        .unwrap();
+    staking_pool.accounted_fee_balance = fee_vault_balance;
+}
+
+/// Move the fee share accrued by a position into its pending rewards
+fn settle_position(staking_pool: &StakingPool, stake_position: &mut StakePosition) {
+    let accumulated = stake_position.amount as u128 * staking_pool.acc_reward_per_share;
+    let unsettled = accumulated - stake_position.reward_debt;
+    let accrued = (unsettled / ACC_REWARD_PRECISION) as u64;
+    
+    stake_position.pending_rewards = stake_position.pending_rewards.checked_add(accrued).unwrap();
+    
+    // Keep the sub-token remainder for the next settlement
+    stake_position.reward_debt = accumulated - unsettled % ACC_REWARD_PRECISION;
+}
+
+/// Reset the reward debt of a position after its staked amount changed
+fn reset_reward_debt(staking_pool: &StakingPool, stake_position: &mut StakePosition) {
+    stake_position.reward_debt = stake_position.amount as u128 * staking_pool.acc_reward_per_share;
+}
+
+/// Stake BodyDFi tokens into the pool
+pub fn stake(
+    ctx: Context<Stake>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, BodyDfiError::InvalidStakeAmount);
+    
+    let staking_pool = &mut ctx.accounts.staking_pool;
+    let stake_position = &mut ctx.accounts.stake_position;
+    let owner = &ctx.accounts.owner;
+    
+    // Create the position on first stake
+    if stake_position.owner == Pubkey::default() {
+        stake_position.owner = owner.key();
+        stake_position.bump = ctx.bumps.stake_position;
+    }
+    
+    // Settle fees accrued at the previous stake amount
+    sync_pool(staking_pool, ctx.accounts.fee_vault.amount);
+    settle_position(staking_pool, stake_position);
+    
+    // Transfer tokens into the stake vault
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.owner_token_account.to_account_info(),
+        to: ctx.accounts.stake_vault.to_account_info(),
+        authority: owner.to_account_info(),
+    };
+    
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+    
+    token::transfer(cpi_ctx, amount)?;
+    
+    // Update stake amounts
+    stake_position.amount = stake_position.amount.checked_add(amount).unwrap();
+    staking_pool.total_staked = staking_pool.total_staked.checked_add(amount).unwrap();
+    reset_reward_debt(staking_pool, stake_position);
+    
+    // Emit event
+    emit!(StakedEvent {
+        owner: owner.key(),
+        amount,
+        total_staked: staking_pool.total_staked,
+    });
+    
+    Ok(())
+}
+
+/// Unstake tokens, starting their unbonding period
+pub fn unstake(
+    ctx: Context<Unstake>,
+    amount: u64,
+) -> Result<()> {
+    let staking_pool = &mut ctx.accounts.staking_pool;
+    let stake_position = &mut ctx.accounts.stake_position;
+    let current_time = ctx.accounts.clock.unix_timestamp;
+    
+    require!(
+        amount > 0 && amount <= stake_position.amount,
+        BodyDfiError::InvalidStakeAmount
+    );
+    
+    // One unbonding at a time, so a new request never re-locks unbonded tokens
+    require!(stake_position.unbonding_amount == 0, BodyDfiError::UnbondingPending);
+    
+    // Settle fees accrued at the previous stake amount
+    sync_pool(staking_pool, ctx.accounts.fee_vault.amount);
+    settle_position(staking_pool, stake_position);
+    
+    // Move the amount into unbonding; unbonding tokens earn no fees
+    stake_position.amount -= amount;
+    staking_pool.total_staked -= amount;
+    stake_position.unbonding_amount = amount;
+    stake_position.unbonding_end = current_time + staking_pool.unbonding_period;
+    reset_reward_debt(staking_pool, stake_position);
+    
+    // Emit event
+    emit!(UnstakedEvent {
+        owner: stake_position.owner,
+        amount,
+        unbonding_end: stake_position.unbonding_end,
+    });
+    
+    Ok(())
+}
+
+/// Withdraw tokens whose unbonding period has ended
+pub fn withdraw_unstaked(ctx: Context<WithdrawUnstaked>) -> Result<()> {
+    let staking_pool = &ctx.accounts.staking_pool;
+    let stake_position = &mut ctx.accounts.stake_position;
+    let current_time = ctx.accounts.clock.unix_timestamp;
+    let amount = stake_position.unbonding_amount;
+    
+    require!(amount > 0, BodyDfiError::InvalidStakeAmount);
+    require!(
+        current_time >= stake_position.unbonding_end,
+        BodyDfiError::UnbondingPeriodActive
+    );
+    
+    stake_position.unbonding_amount = 0;
+    
+    // Transfer from the stake vault, signed by the pool PDA
+    let signer_seeds: &[&[&[u8]]] = &[&[b"staking-pool", &[staking_pool.bump]]];
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.stake_vault.to_account_info(),
+        to: ctx.accounts.owner_token_account.to_account_info(),
+        authority: staking_pool.to_account_info(),
+    };
+    
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+    
+    token::transfer(cpi_ctx, amount)?;
+    
+    // Emit event
+    emit!(UnstakedWithdrawnEvent {
+        owner: stake_position.owner,
+        amount,
+    });
+    
+    Ok(())
+}
+
+/// Claim the accrued share of marketplace fees
+pub fn claim_fee_share(ctx: Context<ClaimFeeShare>) -> Result<()> {
+    let staking_pool = &mut ctx.accounts.staking_pool;
+    let stake_position = &mut ctx.accounts.stake_position;
+    
+    // Settle fees accrued up to now
+    sync_pool(staking_pool, ctx.accounts.fee_vault.amount);
+    settle_position(staking_pool, stake_position);
+    
+    let amount = stake_position.pending_rewards;
+    require!(amount > 0, BodyDfiError::NoPendingRewards);
+    
+    stake_position.pending_rewards = 0;
+    staking_pool.accounted_fee_balance = staking_pool.accounted_fee_balance.checked_sub(amount).unwrap();
+    
+    // Transfer from the fee vault, signed by the pool PDA
+    let signer_seeds: &[&[&[u8]]] = &[&[b"staking-pool", &[staking_pool.bump]]];
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.fee_vault.to_account_info(),
+        to: ctx.accounts.owner_reward_account.to_account_info(),
+        authority: staking_pool.to_account_info(),
+    };
+    
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+    
+    token::transfer(cpi_ctx, amount)?;
+    
+    // Emit event
+    emit!(FeeShareClaimedEvent {
+        owner: stake_position.owner,
+        amount,
+    });
+    
+    Ok(())
+}
+
+/// Event emitted when the staking pool is initialized
+#[event]
+pub struct StakingPoolInitializedEvent {
+    pub stake_mint: Pubkey,
+    pub reward_mint: Pubkey,
+    pub fee_vault: Pubkey,
+    pub unbonding_period: i64,
+}
+
+/// Event emitted when tokens are staked
+#[event]
+pub struct StakedEvent {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub total_staked: u64,
+}
+
+/// Event emitted when tokens start unbonding
+#[event]
+pub struct UnstakedEvent {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub unbonding_end: i64,
+}
+
+/// Event emitted when unbonded tokens are withdrawn
+#[event]
+pub struct UnstakedWithdrawnEvent {
+    pub owner: Pubkey,
+    pub amount: u64,
+}
+
+/// Event emitted when a fee share is claimed
+#[event]
+pub struct FeeShareClaimedEvent {
+    pub owner: Pubkey,
+    pub amount: u64,
+}
\ No newline at end of file
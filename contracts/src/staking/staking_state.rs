@@ -0,0 +1,319 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+/// Scaling factor of the reward-per-share accumulator
+pub const ACC_REWARD_PRECISION: u128 = 1_000_000_000_000;
+
+/// Initialize staking pool account context
+#[derive(Accounts)]
+pub struct InitializeStakingPool<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = program_config.admin == admin.key() @ BodyDfiError::InvalidAuthority
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"staking-pool"],
+        bump,
+        space = 8 + StakingPool::LEN
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+    
+    /// BodyDFi token mint staked into the pool
+    #[account(address = stake_token_mint.mint @ BodyDfiError::InvalidMint)]
+    pub stake_mint: Account<'info, Mint>,
+    
+    /// Program record of the staked mint; pins the pool to the BodyDFi token
+    #[account(
+        seeds = [b"token-mint", stake_token_mint.mint.as_ref()],
+        bump,
+        constraint = !stake_token_mint.is_move_token @ BodyDfiError::InvalidMint
+    )]
+    pub stake_token_mint: Account<'info, TokenMint>,
+    
+    /// Mint the marketplace fees are paid in
+    pub reward_mint: Account<'info, Mint>,
+    
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"stake-vault", staking_pool.key().as_ref()],
+        bump,
+        token::mint = stake_mint,
+        token::authority = staking_pool
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+    
+    /// Vault receiving the token holder share of marketplace fees
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"fee-vault", staking_pool.key().as_ref()],
+        bump,
+        token::mint = reward_mint,
+        token::authority = staking_pool
+    )]
+    pub fee_vault: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Stake account context
+#[derive(Accounts)]
+pub struct Stake<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_REWARDS) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"staking-pool"],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+    
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [b"stake-position", owner.key().as_ref()],
+        bump,
+        space = 8 + StakePosition::LEN
+    )]
+    pub stake_position: Account<'info, StakePosition>,
+    
+    #[account(
+        mut,
+        address = staking_pool.stake_vault @ BodyDfiError::InvalidTokenAccount
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        address = staking_pool.fee_vault @ BodyDfiError::InvalidTokenAccount
+    )]
+    pub fee_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = owner_token_account.owner == owner.key() @ BodyDfiError::InvalidTokenAccount,
+        constraint = owner_token_account.mint == staking_pool.stake_mint @ BodyDfiError::InvalidMint
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Unstake account context
+#[derive(Accounts)]
+pub struct Unstake<'info> {
+    pub owner: Signer<'info>,
+    
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_REWARDS) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"staking-pool"],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+    
+    #[account(
+        mut,
+        seeds = [b"stake-position", owner.key().as_ref()],
+        bump = stake_position.bump,
+        constraint = stake_position.owner == owner.key() @ BodyDfiError::InvalidAuthority
+    )]
+    pub stake_position: Account<'info, StakePosition>,
+    
+    #[account(
+        address = staking_pool.fee_vault @ BodyDfiError::InvalidTokenAccount
+    )]
+    pub fee_vault: Account<'info, TokenAccount>,
+    
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Withdraw unstaked tokens account context
+#[derive(Accounts)]
+pub struct WithdrawUnstaked<'info> {
+    pub owner: Signer<'info>,
+    
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_REWARDS) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(
+        seeds = [b"staking-pool"],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+    
+    #[account(
+        mut,
+        seeds = [b"stake-position", owner.key().as_ref()],
+        bump = stake_position.bump,
+        constraint = stake_position.owner == owner.key() @ BodyDfiError::InvalidAuthority
+    )]
+    pub stake_position: Account<'info, StakePosition>,
+    
+    #[account(
+        mut,
+        address = staking_pool.stake_vault @ BodyDfiError::InvalidTokenAccount
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = owner_token_account.owner == owner.key() @ BodyDfiError::InvalidTokenAccount,
+        constraint = owner_token_account.mint == staking_pool.stake_mint @ BodyDfiError::InvalidMint
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Claim fee share account context
+#[derive(Accounts)]
+pub struct ClaimFeeShare<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_REWARDS) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"staking-pool"],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+    
+    #[account(
+        mut,
+        seeds = [b"stake-position", owner.key().as_ref()],
+        bump = stake_position.bump,
+        constraint = stake_position.owner == owner.key() @ BodyDfiError::InvalidAuthority
+    )]
+    pub stake_position: Account<'info, StakePosition>,
+    
+    #[account(
+        mut,
+        address = staking_pool.fee_vault @ BodyDfiError::InvalidTokenAccount
+    )]
+    pub fee_vault: Account<'info, TokenAccount>,
+    
+    #[account(address = staking_pool.reward_mint @ BodyDfiError::InvalidMint)]
+    pub reward_mint: Account<'info, Mint>,
+    
+    /// Owner's associated token account for the fee mint, created if missing
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = reward_mint,
+        associated_token::authority = owner
+    )]
+    pub owner_reward_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Staking pool distributing the token holder share of marketplace fees
+#[account]
+pub struct StakingPool {
+    /// BodyDFi token mint staked into the pool
+    pub stake_mint: Pubkey,
+    
+    /// Mint the fee share is paid in
+    pub reward_mint: Pubkey,
+    
+    /// Token account holding the staked tokens
+    pub stake_vault: Pubkey,
+    
+    /// Token account receiving the token holder fee share
+    pub fee_vault: Pubkey,
+    
+    /// Total amount actively staked
+    pub total_staked: u64,
+    
+    /// Accumulated fee share per staked token, scaled by ACC_REWARD_PRECISION
+    pub acc_reward_per_share: u128,
+    
+    /// Fee vault balance already reflected in the accumulator
+    pub accounted_fee_balance: u64,
+    
+    /// Time unstaked tokens stay locked before they can be withdrawn
+    pub unbonding_period: i64,
+    
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl StakingPool {
+    pub const LEN: usize = 32 + 32 + 32 + 32 + 8 + 16 + 8 + 8 + 1;
+}
+
+/// Stake position of a single holder
+#[account]
+pub struct StakePosition {
+    /// Owner of the position
+    pub owner: Pubkey,
+    
+    /// Amount actively staked
+    pub amount: u64,
+    
+    /// Accumulator value already accounted for, scaled by ACC_REWARD_PRECISION
+    pub reward_debt: u128,
+    
+    /// Fee share settled and not yet claimed
+    pub pending_rewards: u64,
+    
+    /// Amount unstaked and waiting for the unbonding period to end
+    pub unbonding_amount: u64,
+    
+    /// Timestamp at which the unbonding amount can be withdrawn
+    pub unbonding_end: i64,
+    
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl StakePosition {
+    pub const LEN: usize = 32 + 8 + 16 + 8 + 8 + 8 + 1;
+}
+
+// Import error code
+use crate::errors::BodyDfiError;
+use crate::config::config_state::{ProgramConfig, PAUSE_REWARDS};
+use crate::token::token_state::TokenMint;
\ No newline at end of file
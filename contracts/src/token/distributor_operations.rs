@@ -0,0 +1,237 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
+use anchor_spl::token::{self, Transfer};
+use crate::errors::BodyDfiError;
+use crate::token::token_state::*;
+use crate::token::reward_operations::{draw_emission, mint_move_tokens};
+
+/// Publish a Merkle root of epoch payouts and fund it from the MOVE emission
+pub fn publish_distribution(
+    ctx: Context<PublishDistribution>,
+    epoch: u64,
+    merkle_root: [u8; 32],
+    total_amount: u64,
+    num_leaves: u32,
+) -> Result<()> {
+    // Validate inputs
+    require!(total_amount > 0, BodyDfiError::InvalidDistribution);
+    require!(
+        num_leaves > 0 && num_leaves <= MAX_DISTRIBUTOR_LEAVES,
+        BodyDfiError::InvalidDistribution
+    );
+    
+    let current_time = ctx.accounts.clock.unix_timestamp;
+    
+    // Draw the payout total from the current emission epoch
+    draw_emission(&mut ctx.accounts.emission_schedule, total_amount, current_time)?;
+    
+    // Initialize distributor
+    let distributor = &mut ctx.accounts.distributor;
+    distributor.token_mint = ctx.accounts.token_mint.key();
+    distributor.vault = ctx.accounts.vault.key();
+    distributor.epoch = epoch;
+    distributor.merkle_root = merkle_root;
+    distributor.total_amount = total_amount;
+    distributor.claimed_amount = 0;
+    distributor.num_leaves = num_leaves;
+    distributor.num_claimed = 0;
+    distributor.created_at = current_time;
+    distributor.bump = ctx.bumps.distributor;
+    distributor.claimed_bitmap = vec![0u8; MerkleDistributor::bitmap_len(num_leaves)];
+    
+    // Fund the distributor vault
+    mint_move_tokens(
+        &mut ctx.accounts.token_mint,
+        &ctx.accounts.mint,
+        ctx.accounts.vault.to_account_info(),
+        ctx.accounts.mint_authority.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        total_amount,
+        current_time,
+    )?;
+    
+    // Emit event
+    emit!(DistributionPublishedEvent {
+        distributor: ctx.accounts.distributor.key(),
+        epoch,
+        merkle_root,
+        total_amount,
+        num_leaves,
+    });
+    
+    Ok(())
+}
+
+/// Claim a payout from a distributor by proving its leaf against the root
+pub fn claim_with_proof(
+    ctx: Context<ClaimWithProof>,
+    epoch: u64,
+    index: u32,
+    amount: u64,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let distributor = &mut ctx.accounts.distributor;
+    let claimant = &ctx.accounts.claimant;
+    
+    require!(index < distributor.num_leaves, BodyDfiError::InvalidMerkleProof);
+    require!(!distributor.is_claimed(index), BodyDfiError::RewardAlreadyClaimed);
+    
+    // Verify the leaf against the published root
+    let leaf = distribution_leaf(index, &claimant.key(), amount, epoch);
+    require!(
+        verify_merkle_proof(&proof, distributor.merkle_root, leaf),
+        BodyDfiError::InvalidMerkleProof
+    );
+    
+    // Mark the leaf as claimed before paying out
+    distributor.set_claimed(index);
+    distributor.num_claimed = distributor.num_claimed.checked_add(1).unwrap();
+    distributor.claimed_amount = distributor.claimed_amount.checked_add(amount)
+        .ok_or(BodyDfiError::InvalidDistribution)?;
+    require!(
+        distributor.claimed_amount <= distributor.total_amount,
+        BodyDfiError::InvalidDistribution
+    );
+    
+    // Transfer from the vault, signed by the distributor PDA
+    let token_mint_key = distributor.token_mint;
+    let epoch_bytes = epoch.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"distributor",
+        token_mint_key.as_ref(),
+        &epoch_bytes,
+        &[distributor.bump],
+    ]];
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.vault.to_account_info(),
+        to: ctx.accounts.claimant_token_account.to_account_info(),
+        authority: distributor.to_account_info(),
+    };
+    
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+    
+    token::transfer(cpi_ctx, amount)?;
+    
+    // Emit event
+    emit!(DistributionClaimedEvent {
+        distributor: distributor.key(),
+        claimant: claimant.key(),
+        epoch,
+        index,
+        amount,
+    });
+    
+    Ok(())
+}
+
+/// Hash of a (index, provider, amount, epoch) distribution leaf
+pub fn distribution_leaf(index: u32, provider: &Pubkey, amount: u64, epoch: u64) -> [u8; 32] {
+    keccak::hashv(&[
+        &index.to_le_bytes(),
+        provider.as_ref(),
+        &amount.to_le_bytes(),
+        &epoch.to_le_bytes(),
+    ]).0
+}
+
+/// Verify a Merkle proof built with sorted pair hashing
+pub fn verify_merkle_proof(proof: &[[u8; 32]], root: [u8; 32], leaf: [u8; 32]) -> bool {
+    let mut computed_hash = leaf;
+    
+    for proof_element in proof.iter() {
+        computed_hash = if computed_hash <= *proof_element {
+            keccak::hashv(&[&computed_hash, proof_element]).0
+        } else {
+            keccak::hashv(&[proof_element, &computed_hash]).0
+        };
+    }
+    
+    computed_hash == root
+}
+
+/// Event emitted when a distribution root is published
+#[event]
+pub struct DistributionPublishedEvent {
+    pub distributor: Pubkey,
+    pub epoch: u64,
+    pub merkle_root: [u8; 32],
+    pub total_amount: u64,
+    pub num_leaves: u32,
+}
+
+/// Event emitted when a distribution leaf is claimed
+#[event]
+pub struct DistributionClaimedEvent {
+    pub distributor: Pubkey,
+    pub claimant: Pubkey,
+    pub epoch: u64,
+    pub index: u32,
+    pub amount: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn hash_pair(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+        if a <= b {
+            keccak::hashv(&[&a, &b]).0
+        } else {
+            keccak::hashv(&[&b, &a]).0
+        }
+    }
+    
+    fn leaves() -> Vec<[u8; 32]> {
+        (0..4u32)
+            .map(|index| {
+                let provider = Pubkey::new_from_array([index as u8 + 1; 32]);
+                distribution_leaf(index, &provider, 100 * (index as u64 + 1), 7)
+            })
+            .collect()
+    }
+    
+    #[test]
+    fn single_leaf_tree_needs_empty_proof() {
+        let leaf = leaves()[0];
+        assert!(verify_merkle_proof(&[], leaf, leaf));
+        assert!(!verify_merkle_proof(&[], leaf, leaves()[1]));
+    }
+    
+    #[test]
+    fn every_leaf_of_four_leaf_tree_verifies() {
+        let leaves = leaves();
+        let left = hash_pair(leaves[0], leaves[1]);
+        let right = hash_pair(leaves[2], leaves[3]);
+        let root = hash_pair(left, right);
+        
+        assert!(verify_merkle_proof(&[leaves[1], right], root, leaves[0]));
+        assert!(verify_merkle_proof(&[leaves[0], right], root, leaves[1]));
+        assert!(verify_merkle_proof(&[leaves[3], left], root, leaves[2]));
+        assert!(verify_merkle_proof(&[leaves[2], left], root, leaves[3]));
+    }
+    
+    #[test]
+    fn tampered_proof_or_leaf_is_rejected() {
+        let leaves = leaves();
+        let left = hash_pair(leaves[0], leaves[1]);
+        let right = hash_pair(leaves[2], leaves[3]);
+        let root = hash_pair(left, right);
+        
+        assert!(!verify_merkle_proof(&[leaves[2], right], root, leaves[0]));
+        assert!(!verify_merkle_proof(&[leaves[1]], root, leaves[0]));
+        
+        let forged_leaf = distribution_leaf(0, &Pubkey::new_from_array([1; 32]), 101, 7);
+        assert!(!verify_merkle_proof(&[leaves[1], right], root, forged_leaf));
+    }
+    
+    #[test]
+    fn leaf_binds_every_field() {
+        let provider = Pubkey::new_from_array([1; 32]);
+        let leaf = distribution_leaf(0, &provider, 100, 7);
+        assert_ne!(leaf, distribution_leaf(1, &provider, 100, 7));
+        assert_ne!(leaf, distribution_leaf(0, &Pubkey::new_from_array([2; 32]), 100, 7));
+        assert_ne!(leaf, distribution_leaf(0, &provider, 101, 7));
+        assert_ne!(leaf, distribution_leaf(0, &provider, 100, 8));
+    }
+}
@@ -1,7 +1,11 @@
 pub mod token_operations;
 pub mod reward_operations;
+pub mod distributor_operations;
+pub mod vesting_operations;
 pub mod token_state;
 
 pub use token_operations::*;
 pub use reward_operations::*;
+pub use distributor_operations::*;
+pub use vesting_operations::*;
 pub use token_state::*; 
\ No newline at end of file
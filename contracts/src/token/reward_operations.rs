@@ -2,60 +2,257 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Mint, MintTo};
 use crate::errors::BodyDfiError;
 use crate::token::token_state::*;
+use crate::data_marketplace::data_state::{DataProvider, DataSubmission};
 
 /// Quality score multipliers for rewards
 const BASE_REWARD_MULTIPLIER: u64 = 100;
 const QUALITY_SCORE_MULTIPLIERS: [u64; 5] = [80, 90, 100, 110, 120]; // 0.8x to 1.2x
 
-/// Reward a data provider with MOVE tokens
-pub fn reward_data_provider(
-    ctx: Context<RewardDataProvider>,
-    provider: Pubkey,
-    amount: u64,
-    data_quality_score: u8,
+/// Initialize the MOVE emission schedule
+pub fn initialize_emission_schedule(
+    ctx: Context<InitializeEmissionSchedule>,
+    epoch_length: i64,
+    initial_epoch_budget: u64,
+    decay_bps: u16,
+    decay_interval: u64,
 ) -> Result<()> {
-    // Validate inputs
-    require!(amount > 0, BodyDfiError::InvalidRewardAmount);
-    require!(data_quality_score < 5, BodyDfiError::InvalidDataQualityScore);
+    // Validate schedule parameters
+    require!(epoch_length > 0, BodyDfiError::InvalidEmissionSchedule);
+    require!(initial_epoch_budget > 0, BodyDfiError::InvalidEmissionSchedule);
+    require!(
+        decay_bps as u64 <= BASIS_POINTS_DENOMINATOR,
+        BodyDfiError::InvalidEmissionSchedule
+    );
+    require!(decay_interval > 0, BodyDfiError::InvalidEmissionSchedule);
     
-    let token_mint = &mut ctx.accounts.token_mint;
+    let emission_schedule = &mut ctx.accounts.emission_schedule;
     let current_time = ctx.accounts.clock.unix_timestamp;
     
-    // Check cooldown period
+    // Initialize emission schedule
+    emission_schedule.token_mint = ctx.accounts.token_mint.key();
+    emission_schedule.start_timestamp = current_time;
+    emission_schedule.epoch_length = epoch_length;
+    emission_schedule.initial_epoch_budget = initial_epoch_budget;
+    emission_schedule.decay_bps = decay_bps;
+    emission_schedule.decay_interval = decay_interval;
+    emission_schedule.current_epoch = 0;
+    emission_schedule.current_epoch_budget = initial_epoch_budget;
+    emission_schedule.current_epoch_emitted = 0;
+    emission_schedule.total_emitted = 0;
+    
+    // Emit event
+    emit!(EmissionScheduleInitializedEvent {
+        token_mint: emission_schedule.token_mint,
+        epoch_length,
+        initial_epoch_budget,
+        decay_bps,
+        decay_interval,
+    });
+    
+    Ok(())
+}
+
+/// Draw an amount from the current epoch budget, rolling over to a new
+/// epoch first when the previous one has ended
+pub fn draw_emission(
+    emission_schedule: &mut Account<EmissionSchedule>,
+    amount: u64,
+    current_time: i64,
+) -> Result<()> {
+    let epoch = emission_schedule.epoch_at(current_time);
+    
+    // Roll over to the new epoch with a freshly decayed budget
+    if epoch != emission_schedule.current_epoch {
+        emission_schedule.current_epoch = epoch;
+        emission_schedule.current_epoch_budget = emission_schedule.budget_for_epoch(epoch);
+        emission_schedule.current_epoch_emitted = 0;
+        
+        emit!(EmissionEpochStartedEvent {
+            token_mint: emission_schedule.token_mint,
+            epoch,
+            budget: emission_schedule.current_epoch_budget,
+        });
+    }
+    
+    // Reject the reward once the epoch budget is exhausted
+    let new_epoch_emitted = emission_schedule.current_epoch_emitted.checked_add(amount)
+        .ok_or(BodyDfiError::EpochBudgetExhausted)?;
     require!(
-        current_time - token_mint.last_mint_timestamp >= token_mint.mint_cooldown,
-        BodyDfiError::CooldownPeriodActive
+        new_epoch_emitted <= emission_schedule.current_epoch_budget,
+        BodyDfiError::EpochBudgetExhausted
     );
     
-    // Apply quality score multiplier to reward amount
+    emission_schedule.current_epoch_emitted = new_epoch_emitted;
+    emission_schedule.total_emitted = emission_schedule.total_emitted.checked_add(amount)
+        .ok_or(BodyDfiError::InvalidRewardAmount)?;
+    
+    Ok(())
+}
+
+/// Apply the quality score multiplier to a base reward amount
+pub fn apply_quality_multiplier(amount: u64, data_quality_score: u8) -> Result<u64> {
+    require!(data_quality_score < 5, BodyDfiError::InvalidDataQualityScore);
+    
     let quality_multiplier = QUALITY_SCORE_MULTIPLIERS[data_quality_score as usize];
     let adjusted_amount = amount.checked_mul(quality_multiplier)
-        .unwrap()
+        .ok_or(BodyDfiError::InvalidRewardAmount)?
         .checked_div(BASE_REWARD_MULTIPLIER)
         .unwrap();
     
-    // Update token mint state
-    token_mint.circulating_supply = token_mint.circulating_supply.checked_add(adjusted_amount)
-        .ok_or(BodyDfiError::MintingCapExceeded)?;
+    Ok(adjusted_amount)
+}
+
+/// Check that a submission is eligible for a reward and record the reward
+/// against the submission, the provider and the emission schedule
+fn record_submission_reward(
+    token_mint: &mut Account<TokenMint>,
+    emission_schedule: &mut Account<EmissionSchedule>,
+    data_provider: &mut Account<DataProvider>,
+    data_submission: &mut Account<DataSubmission>,
+    amount: u64,
+    current_time: i64,
+) -> Result<u64> {
+    // Validate inputs
+    require!(amount > 0, BodyDfiError::InvalidRewardAmount);
     
+    // Only validated submissions that were not rewarded yet are eligible
+    require!(
+        data_submission.is_validated,
+        BodyDfiError::SubmissionNotValidated
+    );
+    require!(
+        !data_submission.is_rewarded,
+        BodyDfiError::SubmissionAlreadyRewarded
+    );
+    
+    // Check the provider's own cooldown period
+    require!(
+        current_time - data_provider.last_reward_timestamp >= token_mint.mint_cooldown,
+        BodyDfiError::CooldownPeriodActive
+    );
+    
+    // Apply the validated quality score multiplier to reward amount
+    let adjusted_amount = apply_quality_multiplier(amount, data_submission.quality_score)?;
+    
+    // Draw the reward from the current emission epoch
+    draw_emission(emission_schedule, adjusted_amount, current_time)?;
+    
+    // Update provider and submission state
+    data_provider.last_reward_timestamp = current_time;
+    data_provider.total_rewards = data_provider.total_rewards.checked_add(adjusted_amount).unwrap();
+    data_submission.is_rewarded = true;
+    
+    Ok(adjusted_amount)
+}
+
+/// Mint MOVE tokens through the program mint authority PDA, enforcing the
+/// mint cap against the cumulative minted supply and tracking circulating supply
+pub fn mint_move_tokens<'info>(
+    token_mint: &mut Account<'info, TokenMint>,
+    mint: &Account<'info, Mint>,
+    to: AccountInfo<'info>,
+    mint_authority: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    amount: u64,
+    current_time: i64,
+) -> Result<()> {
+    // Update token mint state, enforcing the cap when one is set; burns do
+    // not reduce the minted total, so they never reopen minting headroom
+    let new_total_supply = token_mint.total_supply.checked_add(amount)
+        .ok_or(BodyDfiError::MintingCapExceeded)?;
+    require!(
+        token_mint.mint_cap == 0 || new_total_supply <= token_mint.mint_cap,
+        BodyDfiError::MintingCapExceeded
+    );
+    token_mint.total_supply = new_total_supply;
+    token_mint.circulating_supply = token_mint.circulating_supply.checked_add(amount)
+        .ok_or(BodyDfiError::MintingCapExceeded)?;
     token_mint.last_mint_timestamp = current_time;
     
-    // Create the mint to instruction
+    // Create the mint to instruction, signed by the program mint authority PDA
+    let signer_seeds: &[&[&[u8]]] = &[&[b"mint-authority", &[token_mint.mint_authority_bump]]];
     let cpi_accounts = MintTo {
-        mint: ctx.accounts.token_mint.to_account_info(),
-        to: ctx.accounts.provider_token_account.to_account_info(),
-        authority: ctx.accounts.authority.to_account_info(),
+        mint: mint.to_account_info(),
+        to,
+        authority: mint_authority,
     };
     
-    let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+    let cpi_ctx = CpiContext::new_with_signer(token_program, cpi_accounts, signer_seeds);
+    
+    token::mint_to(cpi_ctx, amount)
+}
+
+/// Reward a data provider with MOVE tokens for a validated data submission
+pub fn reward_data_provider(
+    ctx: Context<RewardDataProvider>,
+    amount: u64,
+) -> Result<()> {
+    let current_time = ctx.accounts.clock.unix_timestamp;
+    let data_quality_score = ctx.accounts.data_submission.quality_score;
+    
+    // Validate and record the reward
+    let adjusted_amount = record_submission_reward(
+        &mut ctx.accounts.token_mint,
+        &mut ctx.accounts.emission_schedule,
+        &mut ctx.accounts.data_provider,
+        &mut ctx.accounts.data_submission,
+        amount,
+        current_time,
+    )?;
     
     // Mint tokens to the provider
-    token::mint_to(cpi_ctx, adjusted_amount)?;
+    mint_move_tokens(
+        &mut ctx.accounts.token_mint,
+        &ctx.accounts.mint,
+        ctx.accounts.provider_token_account.to_account_info(),
+        ctx.accounts.mint_authority.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        adjusted_amount,
+        current_time,
+    )?;
+    
+    // Emit event
+    emit!(RewardEvent {
+        provider: ctx.accounts.data_provider.authority,
+        amount: adjusted_amount,
+        data_quality_score,
+        timestamp: current_time,
+    });
+    
+    Ok(())
+}
+
+/// Accrue a reward for a validated data submission into the provider's
+/// pending rewards, to be claimed later by the provider
+pub fn accrue_reward(
+    ctx: Context<AccrueReward>,
+    amount: u64,
+) -> Result<()> {
+    let current_time = ctx.accounts.clock.unix_timestamp;
+    let data_quality_score = ctx.accounts.data_submission.quality_score;
+    
+    // Validate and record the reward
+    let adjusted_amount = record_submission_reward(
+        &mut ctx.accounts.token_mint,
+        &mut ctx.accounts.emission_schedule,
+        &mut ctx.accounts.data_provider,
+        &mut ctx.accounts.data_submission,
+        amount,
+        current_time,
+    )?;
+    
+    // Credit the pending balance
+    let pending_rewards = &mut ctx.accounts.pending_rewards;
+    if pending_rewards.provider == Pubkey::default() {
+        pending_rewards.provider = ctx.accounts.data_provider.authority;
+        pending_rewards.token_mint = ctx.accounts.token_mint.key();
+    }
+    pending_rewards.accrued_amount = pending_rewards.accrued_amount.checked_add(adjusted_amount).unwrap();
+    pending_rewards.last_accrued_at = current_time;
     
     // Emit event
     emit!(RewardEvent {
-        provider,
+        provider: pending_rewards.provider,
         amount: adjusted_amount,
         data_quality_score,
         timestamp: current_time,
@@ -64,6 +261,40 @@ pub fn reward_data_provider(
     Ok(())
 }
 
+/// Claim all pending rewards into the provider's token account
+pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
+    let current_time = ctx.accounts.clock.unix_timestamp;
+    let pending_rewards = &mut ctx.accounts.pending_rewards;
+    let amount = pending_rewards.accrued_amount;
+    
+    require!(amount > 0, BodyDfiError::NoPendingRewards);
+    
+    // Clear the pending balance before minting
+    pending_rewards.accrued_amount = 0;
+    pending_rewards.total_claimed = pending_rewards.total_claimed.checked_add(amount).unwrap();
+    pending_rewards.last_claimed_at = current_time;
+    
+    // Mint the accrued balance to the provider
+    mint_move_tokens(
+        &mut ctx.accounts.token_mint,
+        &ctx.accounts.mint,
+        ctx.accounts.provider_token_account.to_account_info(),
+        ctx.accounts.mint_authority.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        amount,
+        current_time,
+    )?;
+    
+    // Emit event
+    emit!(RewardsClaimedEvent {
+        provider: ctx.accounts.provider.key(),
+        amount,
+        timestamp: current_time,
+    });
+    
+    Ok(())
+}
+
 /// Event emitted when a provider is rewarded
 #[event]
 pub struct RewardEvent {
@@ -71,4 +302,30 @@ pub struct RewardEvent {
     pub amount: u64,
     pub data_quality_score: u8,
     pub timestamp: i64,
-} 
\ No newline at end of file
+}
+
+/// Event emitted when a provider claims pending rewards
+#[event]
+pub struct RewardsClaimedEvent {
+    pub provider: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+/// Event emitted when the emission schedule is initialized
+#[event]
+pub struct EmissionScheduleInitializedEvent {
+    pub token_mint: Pubkey,
+    pub epoch_length: i64,
+    pub initial_epoch_budget: u64,
+    pub decay_bps: u16,
+    pub decay_interval: u64,
+}
+
+/// Event emitted when a new emission epoch begins
+#[event]
+pub struct EmissionEpochStartedEvent {
+    pub token_mint: Pubkey,
+    pub epoch: u64,
+    pub budget: u64,
+}
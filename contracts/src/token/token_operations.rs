@@ -1,13 +1,11 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, Token, MintTo};
+use anchor_spl::token::{self, Burn, MintTo};
 use crate::errors::BodyDfiError;
 use crate::token::token_state::*;
+use crate::config::config_operations::{AuthorityTransferAcceptedEvent, AuthorityTransferProposedEvent};
 
 /// Token configuration constants
 const MOVE_TOKEN_MINT_COOLDOWN: i64 = 3600; // 1 hour in seconds
-const MOVE_TOKEN_DECIMALS: u8 = 9;
-const BODYDFI_TOKEN_DECIMALS: u8 = 9;
-const BODYDFI_TOTAL_SUPPLY: u64 = 1_000_000_000_000_000_000; // 1 billion with 9 decimals
 
 /// Initialize the MOVE token
 pub fn initialize_move_token(
@@ -22,6 +20,7 @@ pub fn initialize_move_token(
     
     // Set up the MOVE token parameters
     token_mint.authority = authority.key();
+    token_mint.mint = ctx.accounts.mint.key();
     token_mint.name = name;
     token_mint.symbol = symbol;
     token_mint.uri = uri;
@@ -31,27 +30,19 @@ pub fn initialize_move_token(
     token_mint.is_move_token = true;
     token_mint.last_mint_timestamp = 0;
     token_mint.mint_cooldown = MOVE_TOKEN_MINT_COOLDOWN;
-    token_mint.mint_cap = 0; // No cap for utility token, controlled by rate limiting
+    token_mint.mint_cap = 0; // No cap for utility token, controlled by the emission schedule
+    token_mint.mint_authority_bump = ctx.bumps.mint_authority;
+    token_mint.pending_authority = Pubkey::default();
     
-    // Create the token mint using SPL token program
-    let cpi_accounts = token::MintTo {
-        mint: ctx.accounts.token_mint.to_account_info(),
-        to: authority.to_account_info(),
-        authority: authority.to_account_info(),
-    };
-    
-    let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-    
-    // Initialize the mint
-    token::mint_to(cpi_ctx, 0)?;
+    // The SPL mint itself is created by the account constraints with the
+    // program PDA as mint authority, so no supply is minted here
     
     Ok(())
 }
 
 /// Initialize the BodyDFi token
 pub fn initialize_bodydfi_token(
-    ctx: Context<InitializeToken>,
+    ctx: Context<InitializeBodyDfiToken>,
     name: String,
     symbol: String,
     uri: String,
@@ -60,9 +51,11 @@ pub fn initialize_bodydfi_token(
 ) -> Result<()> {
     let token_mint = &mut ctx.accounts.token_mint;
     let authority = &ctx.accounts.authority;
+    let mint_authority_bump = ctx.bumps.mint_authority;
     
     // Set up the BodyDFi token parameters
     token_mint.authority = authority.key();
+    token_mint.mint = ctx.accounts.mint.key();
     token_mint.name = name;
     token_mint.symbol = symbol;
     token_mint.uri = uri;
@@ -73,19 +66,132 @@ pub fn initialize_bodydfi_token(
     token_mint.last_mint_timestamp = 0;
     token_mint.mint_cooldown = 0; // No cooldown for governance token
     token_mint.mint_cap = total_supply; // Cap at total supply
+    token_mint.mint_authority_bump = mint_authority_bump;
+    token_mint.pending_authority = Pubkey::default();
     
-    // Create the token mint using SPL token program
-    let cpi_accounts = token::MintTo {
-        mint: ctx.accounts.token_mint.to_account_info(),
-        to: authority.to_account_info(),
-        authority: authority.to_account_info(),
+    // Mint through the program PDA, which is the SPL mint authority
+    let signer_seeds: &[&[&[u8]]] = &[&[b"mint-authority", &[mint_authority_bump]]];
+    let cpi_accounts = MintTo {
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.allocation_vault.to_account_info(),
+        authority: ctx.accounts.mint_authority.to_account_info(),
     };
     
     let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
     
-    // Mint the full supply to the authority
+    // Mint the full supply to the allocation vault, from which it is split
+    // into vesting schedules
     token::mint_to(cpi_ctx, total_supply)?;
     
     Ok(())
-} 
\ No newline at end of file
+}
+
+/// Propose a new token authority; the default pubkey cancels a pending proposal
+pub fn propose_authority_transfer(
+    ctx: Context<ProposeAuthorityTransfer>,
+    new_authority: Pubkey,
+) -> Result<()> {
+    let token_mint = &mut ctx.accounts.token_mint;
+    token_mint.pending_authority = new_authority;
+    
+    // Emit event
+    emit!(AuthorityTransferProposedEvent {
+        account: token_mint.key(),
+        current_authority: token_mint.authority,
+        pending_authority: new_authority,
+    });
+    
+    Ok(())
+}
+
+/// Accept a pending token authority transfer
+pub fn accept_authority_transfer(ctx: Context<AcceptAuthorityTransfer>) -> Result<()> {
+    let token_mint = &mut ctx.accounts.token_mint;
+    let previous_authority = token_mint.authority;
+    
+    token_mint.authority = token_mint.pending_authority;
+    token_mint.pending_authority = Pubkey::default();
+    
+    // Emit event
+    emit!(AuthorityTransferAcceptedEvent {
+        account: token_mint.key(),
+        previous_authority,
+        new_authority: token_mint.authority,
+    });
+    
+    Ok(())
+}
+
+/// Burn tokens from the holder's account and track the reduced supply
+pub fn burn_tokens(
+    ctx: Context<BurnTokens>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, BodyDfiError::InvalidBurnAmount);
+    
+    let token_mint = &mut ctx.accounts.token_mint;
+    let owner = &ctx.accounts.owner;
+    
+    // Update token mint state
+    token_mint.circulating_supply = token_mint.circulating_supply.checked_sub(amount)
+        .ok_or(BodyDfiError::InsufficientFunds)?;
+    
+    // Burn the tokens, signed by the holder
+    let cpi_accounts = Burn {
+        mint: ctx.accounts.mint.to_account_info(),
+        from: ctx.accounts.owner_token_account.to_account_info(),
+        authority: owner.to_account_info(),
+    };
+    
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+    
+    token::burn(cpi_ctx, amount)?;
+    
+    // Emit event
+    emit!(TokensBurnedEvent {
+        mint: token_mint.mint,
+        owner: owner.key(),
+        amount,
+        circulating_supply: token_mint.circulating_supply,
+    });
+    
+    Ok(())
+}
+
+/// Compare the recorded circulating supply with the SPL mint supply
+pub fn reconcile_supply(ctx: Context<ReconcileSupply>) -> Result<()> {
+    let token_mint = &ctx.accounts.token_mint;
+    let recorded_supply = token_mint.circulating_supply;
+    let onchain_supply = ctx.accounts.mint.supply;
+    
+    if recorded_supply != onchain_supply {
+        emit!(SupplyDiscrepancyEvent {
+            mint: token_mint.mint,
+            recorded_supply,
+            onchain_supply,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+    }
+    
+    Ok(())
+}
+
+/// Event emitted when tokens are burned
+#[event]
+pub struct TokensBurnedEvent {
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub circulating_supply: u64,
+}
+
+/// Event emitted when the recorded supply does not match the SPL mint
+#[event]
+pub struct SupplyDiscrepancyEvent {
+    pub mint: Pubkey,
+    pub recorded_supply: u64,
+    pub onchain_supply: u64,
+    pub timestamp: i64,
+}
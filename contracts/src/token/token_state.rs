@@ -1,23 +1,97 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::TokenAccount;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+/// Denominator for basis point values
+pub const BASIS_POINTS_DENOMINATOR: u64 = 10_000;
+
+/// Fixed-point precision of the retained fraction in emission decay
+const DECAY_PRECISION: u128 = 1_000_000_000_000_000_000;
 
 /// Token initialization account context
 #[derive(Accounts)]
+#[instruction(name: String, symbol: String, uri: String, decimals: u8)]
 pub struct InitializeToken<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
     
+    /// Program PDA that holds the mint authority of every program token
+    /// CHECK: PDA only used as a signer for mint CPIs
+    #[account(
+        seeds = [b"mint-authority"],
+        bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    /// SPL mint backing this token
+    #[account(
+        init,
+        payer = authority,
+        mint::decimals = decimals,
+        mint::authority = mint_authority
+    )]
+    pub mint: Account<'info, Mint>,
+    
+    #[account(
+        init,
+        payer = authority,
+        seeds = [b"token-mint", mint.key().as_ref()],
+        bump,
+        space = 8 + TokenMint::LEN
+    )]
+    pub token_mint: Account<'info, TokenMint>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// BodyDFi token initialization account context
+#[derive(Accounts)]
+#[instruction(name: String, symbol: String, uri: String, decimals: u8)]
+pub struct InitializeBodyDfiToken<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// Program PDA that holds the mint authority of every program token
+    /// CHECK: PDA only used as a signer for mint CPIs
+    #[account(
+        seeds = [b"mint-authority"],
+        bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    /// SPL mint backing this token
+    #[account(
+        init,
+        payer = authority,
+        mint::decimals = decimals,
+        mint::authority = mint_authority
+    )]
+    pub mint: Account<'info, Mint>,
+    
     #[account(
         init,
         payer = authority,
-        seeds = [b"token-mint", authority.key().as_ref()],
+        seeds = [b"token-mint", mint.key().as_ref()],
         bump,
         space = 8 + TokenMint::LEN
     )]
     pub token_mint: Account<'info, TokenMint>,
     
+    /// Program-owned vault holding the unallocated supply
+    #[account(
+        init,
+        payer = authority,
+        seeds = [b"allocation-vault", mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = token_mint
+    )]
+    pub allocation_vault: Account<'info, TokenAccount>,
+    
     pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, anchor_spl::token::Token>,
+    pub token_program: Program<'info, Token>,
     pub rent: Sysvar<'info, Rent>,
 }
 
@@ -30,16 +104,118 @@ pub struct TokenMint {
     pub symbol: String,
     pub uri: String,
     pub decimals: u8,
+    /// Cumulative supply minted; burns do not reduce it
     pub total_supply: u64,
+    /// Minted supply less burned tokens
     pub circulating_supply: u64,
     pub is_move_token: bool,
     pub last_mint_timestamp: i64,
+    /// Minimum interval between two rewards to the same provider
     pub mint_cooldown: i64,
     pub mint_cap: u64,
+    pub mint_authority_bump: u8,
+    /// Authority proposed by the current authority, pending acceptance
+    pub pending_authority: Pubkey,
 }
 
 impl TokenMint {
-    pub const LEN: usize = 32 + 32 + 64 + 16 + 200 + 1 + 8 + 8 + 1 + 8 + 8 + 8;
+    pub const LEN: usize = 32 + 32 + 64 + 16 + 200 + 1 + 8 + 8 + 1 + 8 + 8 + 8 + 1 + 32;
+}
+
+/// Initialize emission schedule account context
+#[derive(Accounts)]
+pub struct InitializeEmissionSchedule<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// Move token mint account
+    #[account(
+        constraint = token_mint.is_move_token @ BodyDfiError::InvalidMint,
+        constraint = token_mint.authority == authority.key() @ BodyDfiError::InvalidAuthority
+    )]
+    pub token_mint: Account<'info, TokenMint>,
+    
+    #[account(
+        init,
+        payer = authority,
+        seeds = [b"emission-schedule", token_mint.key().as_ref()],
+        bump,
+        space = 8 + EmissionSchedule::LEN
+    )]
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+    
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Emission schedule account controlling MOVE issuance per epoch
+#[account]
+pub struct EmissionSchedule {
+    /// Token mint account this schedule emits for
+    pub token_mint: Pubkey,
+    
+    /// Timestamp at which epoch 0 started
+    pub start_timestamp: i64,
+    
+    /// Epoch length in seconds
+    pub epoch_length: i64,
+    
+    /// Budget of the first epoch
+    pub initial_epoch_budget: u64,
+    
+    /// Budget reduction applied every decay interval, in basis points
+    pub decay_bps: u16,
+    
+    /// Number of epochs between budget reductions (5000 bps gives halvings)
+    pub decay_interval: u64,
+    
+    /// Epoch the current budget applies to
+    pub current_epoch: u64,
+    
+    /// Budget of the current epoch
+    pub current_epoch_budget: u64,
+    
+    /// Amount already emitted in the current epoch
+    pub current_epoch_emitted: u64,
+    
+    /// Amount emitted over the lifetime of the schedule
+    pub total_emitted: u64,
+}
+
+impl EmissionSchedule {
+    pub const LEN: usize = 32 + 8 + 8 + 8 + 2 + 8 + 8 + 8 + 8 + 8;
+    
+    /// Epoch number for a given timestamp
+    pub fn epoch_at(&self, timestamp: i64) -> u64 {
+        if timestamp <= self.start_timestamp {
+            return 0;
+        }
+        ((timestamp - self.start_timestamp) / self.epoch_length) as u64
+    }
+    
+    /// Budget of a given epoch after applying the decay schedule, computing the
+    /// retained fraction by exponentiation by squaring so the cost is bounded
+    /// by the bit length of the number of decay steps
+    pub fn budget_for_epoch(&self, epoch: u64) -> u64 {
+        if self.decay_bps == 0 {
+            return self.initial_epoch_budget;
+        }
+        
+        let mut decay_steps = epoch / self.decay_interval;
+        let retained_bps = BASIS_POINTS_DENOMINATOR - self.decay_bps as u64;
+        let mut base = retained_bps as u128 * DECAY_PRECISION / BASIS_POINTS_DENOMINATOR as u128;
+        let mut retained = DECAY_PRECISION;
+        
+        while decay_steps > 0 && retained > 0 {
+            if decay_steps & 1 == 1 {
+                retained = retained * base / DECAY_PRECISION;
+            }
+            base = base * base / DECAY_PRECISION;
+            decay_steps >>= 1;
+        }
+        
+        (self.initial_epoch_budget as u128 * retained / DECAY_PRECISION) as u64
+    }
 }
 
 /// Reward data provider account context
@@ -48,9 +224,29 @@ pub struct RewardDataProvider<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
     
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_REWARDS) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
     /// Data provider receiving the reward
-    /// CHECK: Validated in the handler
-    pub provider: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"data-provider", data_provider.user_id.as_bytes()],
+        bump
+    )]
+    pub data_provider: Account<'info, DataProvider>,
+    
+    /// Validated data submission being rewarded
+    #[account(
+        mut,
+        seeds = [b"data-submission", data_submission.data_hash.as_bytes()],
+        bump,
+        constraint = data_submission.provider == data_provider.authority @ BodyDfiError::InvalidDataProvider
+    )]
+    pub data_submission: Account<'info, DataSubmission>,
     
     /// Move token mint account
     #[account(
@@ -60,13 +256,781 @@ pub struct RewardDataProvider<'info> {
     )]
     pub token_mint: Account<'info, TokenMint>,
     
+    /// SPL mint of the MOVE token
+    #[account(
+        mut,
+        address = token_mint.mint @ BodyDfiError::InvalidMint
+    )]
+    pub mint: Account<'info, Mint>,
+    
+    /// Program PDA that signs the mint CPI
+    /// CHECK: Seeds are verified against the stored bump
+    #[account(
+        seeds = [b"mint-authority"],
+        bump = token_mint.mint_authority_bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    /// Emission schedule the reward is drawn from
+    #[account(
+        mut,
+        seeds = [b"emission-schedule", token_mint.key().as_ref()],
+        bump
+    )]
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+    
     /// Provider's token account
+    #[account(
+        mut,
+        constraint = provider_token_account.owner == data_provider.authority @ BodyDfiError::InvalidTokenAccount,
+        constraint = provider_token_account.mint == token_mint.mint @ BodyDfiError::InvalidMint
+    )]
+    pub provider_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Accrue reward account context
+#[derive(Accounts)]
+pub struct AccrueReward<'info> {
     #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_REWARDS) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    /// Move token mint account
+    #[account(
+        mut,
+        constraint = token_mint.is_move_token @ BodyDfiError::InvalidMint,
+        constraint = token_mint.authority == authority.key() @ BodyDfiError::InvalidAuthority
+    )]
+    pub token_mint: Account<'info, TokenMint>,
+    
+    /// Emission schedule the reward is drawn from
+    #[account(
+        mut,
+        seeds = [b"emission-schedule", token_mint.key().as_ref()],
+        bump
+    )]
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+    
+    /// Data provider accruing the reward
+    #[account(
+        mut,
+        seeds = [b"data-provider", data_provider.user_id.as_bytes()],
+        bump
+    )]
+    pub data_provider: Account<'info, DataProvider>,
+    
+    /// Validated data submission being rewarded
+    #[account(
+        mut,
+        seeds = [b"data-submission", data_submission.data_hash.as_bytes()],
+        bump,
+        constraint = data_submission.provider == data_provider.authority @ BodyDfiError::InvalidDataProvider
+    )]
+    pub data_submission: Account<'info, DataSubmission>,
+    
+    /// Provider's pending rewards, created on first accrual
+    #[account(
+        init_if_needed,
+        payer = authority,
+        seeds = [b"pending-rewards", token_mint.key().as_ref(), data_provider.authority.as_ref()],
+        bump,
+        space = 8 + PendingRewards::LEN
+    )]
+    pub pending_rewards: Account<'info, PendingRewards>,
+    
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Claim rewards account context
+#[derive(Accounts)]
+pub struct ClaimRewards<'info> {
+    #[account(mut)]
+    pub provider: Signer<'info>,
+    
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_REWARDS) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    /// Move token mint account
+    #[account(
+        mut,
+        constraint = token_mint.is_move_token @ BodyDfiError::InvalidMint
+    )]
+    pub token_mint: Account<'info, TokenMint>,
+    
+    /// SPL mint of the MOVE token
+    #[account(
+        mut,
+        address = token_mint.mint @ BodyDfiError::InvalidMint
+    )]
+    pub mint: Account<'info, Mint>,
+    
+    /// Program PDA that signs the mint CPI
+    /// CHECK: Seeds are verified against the stored bump
+    #[account(
+        seeds = [b"mint-authority"],
+        bump = token_mint.mint_authority_bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"pending-rewards", token_mint.key().as_ref(), provider.key().as_ref()],
+        bump,
+        constraint = pending_rewards.provider == provider.key() @ BodyDfiError::InvalidAuthority
+    )]
+    pub pending_rewards: Account<'info, PendingRewards>,
+    
+    /// Provider's associated token account, created if missing
+    #[account(
+        init_if_needed,
+        payer = provider,
+        associated_token::mint = mint,
+        associated_token::authority = provider
+    )]
     pub provider_token_account: Account<'info, TokenAccount>,
     
-    pub token_program: Program<'info, anchor_spl::token::Token>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Pending rewards accrued to a provider and not yet claimed
+#[account]
+pub struct PendingRewards {
+    /// Provider authority entitled to claim
+    pub provider: Pubkey,
+    
+    /// Token mint account the rewards are paid in
+    pub token_mint: Pubkey,
+    
+    /// Amount accrued and not yet claimed
+    pub accrued_amount: u64,
+    
+    /// Amount claimed over the lifetime of the account
+    pub total_claimed: u64,
+    
+    /// Last accrual timestamp
+    pub last_accrued_at: i64,
+    
+    /// Last claim timestamp
+    pub last_claimed_at: i64,
+}
+
+impl PendingRewards {
+    pub const LEN: usize = 32 + 32 + 8 + 8 + 8 + 8;
+}
+
+/// Publish distribution account context
+#[derive(Accounts)]
+#[instruction(epoch: u64, merkle_root: [u8; 32], total_amount: u64, num_leaves: u32)]
+pub struct PublishDistribution<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_REWARDS) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    /// Move token mint account
+    #[account(
+        mut,
+        constraint = token_mint.is_move_token @ BodyDfiError::InvalidMint,
+        constraint = token_mint.authority == authority.key() @ BodyDfiError::InvalidAuthority
+    )]
+    pub token_mint: Account<'info, TokenMint>,
+    
+    /// SPL mint of the MOVE token
+    #[account(
+        mut,
+        address = token_mint.mint @ BodyDfiError::InvalidMint
+    )]
+    pub mint: Account<'info, Mint>,
+    
+    /// Program PDA that signs the mint CPI
+    /// CHECK: Seeds are verified against the stored bump
+    #[account(
+        seeds = [b"mint-authority"],
+        bump = token_mint.mint_authority_bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    /// Emission schedule the payout is drawn from
+    #[account(
+        mut,
+        seeds = [b"emission-schedule", token_mint.key().as_ref()],
+        bump
+    )]
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+    
+    #[account(
+        init,
+        payer = authority,
+        seeds = [b"distributor", token_mint.key().as_ref(), &epoch.to_le_bytes()],
+        bump,
+        space = 8 + MerkleDistributor::LEN + MerkleDistributor::bitmap_len(num_leaves)
+    )]
+    pub distributor: Account<'info, MerkleDistributor>,
+    
+    /// Token account holding the distributor's funds
+    #[account(
+        init,
+        payer = authority,
+        seeds = [b"distributor-vault", distributor.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = distributor
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Claim with proof account context
+#[derive(Accounts)]
+#[instruction(epoch: u64)]
+pub struct ClaimWithProof<'info> {
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+    
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_REWARDS) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"distributor", distributor.token_mint.as_ref(), &epoch.to_le_bytes()],
+        bump = distributor.bump
+    )]
+    pub distributor: Account<'info, MerkleDistributor>,
+    
+    #[account(
+        mut,
+        address = distributor.vault @ BodyDfiError::InvalidTokenAccount
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    
+    /// SPL mint of the distributed token
+    #[account(address = vault.mint @ BodyDfiError::InvalidMint)]
+    pub mint: Account<'info, Mint>,
+    
+    /// Claimant's associated token account, created if missing
+    #[account(
+        init_if_needed,
+        payer = claimant,
+        associated_token::mint = mint,
+        associated_token::authority = claimant
+    )]
+    pub claimant_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Merkle distributor holding the payouts of one reward epoch
+#[account]
+pub struct MerkleDistributor {
+    /// Token mint account the payouts are made in
+    pub token_mint: Pubkey,
+    
+    /// Token account holding the funds
+    pub vault: Pubkey,
+    
+    /// Payout epoch covered by this distributor
+    pub epoch: u64,
+    
+    /// Root of the (index, provider, amount, epoch) leaves
+    pub merkle_root: [u8; 32],
+    
+    /// Total amount funded
+    pub total_amount: u64,
+    
+    /// Total amount claimed so far
+    pub claimed_amount: u64,
+    
+    /// Number of leaves in the tree
+    pub num_leaves: u32,
+    
+    /// Number of leaves claimed so far
+    pub num_claimed: u32,
+    
+    /// Created timestamp
+    pub created_at: i64,
+    
+    /// PDA bump
+    pub bump: u8,
+    
+    /// One bit per leaf, set once the leaf has been claimed
+    pub claimed_bitmap: Vec<u8>,
+}
+
+/// Maximum number of leaves per distributor (bounded by account size)
+pub const MAX_DISTRIBUTOR_LEAVES: u32 = 65_536;
+
+impl MerkleDistributor {
+    /// Fixed size, excluding the bitmap bytes
+    pub const LEN: usize = 32 + 32 + 8 + 32 + 8 + 8 + 4 + 4 + 8 + 1 + 4;
+    
+    /// Number of bitmap bytes needed for a number of leaves
+    pub fn bitmap_len(num_leaves: u32) -> usize {
+        (num_leaves as usize).div_ceil(8)
+    }
+    
+    /// Whether a leaf has already been claimed
+    pub fn is_claimed(&self, index: u32) -> bool {
+        let byte = self.claimed_bitmap[(index / 8) as usize];
+        byte & (1 << (index % 8)) != 0
+    }
+    
+    /// Mark a leaf as claimed
+    pub fn set_claimed(&mut self, index: u32) {
+        self.claimed_bitmap[(index / 8) as usize] |= 1 << (index % 8);
+    }
+}
+
+/// Create vesting account context
+#[derive(Accounts)]
+#[instruction(vesting_id: u64, beneficiary: Pubkey)]
+pub struct CreateVesting<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_REWARDS) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    /// BodyDFi token mint account, signer of the allocation vault
+    #[account(
+        seeds = [b"token-mint", mint.key().as_ref()],
+        bump,
+        constraint = !token_mint.is_move_token @ BodyDfiError::InvalidMint,
+        constraint = token_mint.authority == authority.key() @ BodyDfiError::InvalidAuthority
+    )]
+    pub token_mint: Account<'info, TokenMint>,
+    
+    #[account(address = token_mint.mint @ BodyDfiError::InvalidMint)]
+    pub mint: Account<'info, Mint>,
+    
+    #[account(
+        mut,
+        seeds = [b"allocation-vault", mint.key().as_ref()],
+        bump
+    )]
+    pub allocation_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        init,
+        payer = authority,
+        seeds = [b"vesting", token_mint.key().as_ref(), beneficiary.as_ref(), &vesting_id.to_le_bytes()],
+        bump,
+        space = 8 + VestingSchedule::LEN
+    )]
+    pub vesting: Account<'info, VestingSchedule>,
+    
+    /// Escrow token account holding the vesting tokens
+    #[account(
+        init,
+        payer = authority,
+        seeds = [b"vesting-vault", vesting.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = vesting
+    )]
+    pub vesting_vault: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
     pub clock: Sysvar<'info, Clock>,
 }
 
+/// Withdraw vested tokens account context
+#[derive(Accounts)]
+pub struct WithdrawVested<'info> {
+    #[account(mut)]
+    pub beneficiary: Signer<'info>,
+    
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_REWARDS) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"vesting", vesting.token_mint.as_ref(), beneficiary.key().as_ref(), &vesting.vesting_id.to_le_bytes()],
+        bump = vesting.bump,
+        constraint = vesting.beneficiary == beneficiary.key() @ BodyDfiError::InvalidAuthority
+    )]
+    pub vesting: Account<'info, VestingSchedule>,
+    
+    #[account(
+        mut,
+        address = vesting.vault @ BodyDfiError::InvalidTokenAccount
+    )]
+    pub vesting_vault: Account<'info, TokenAccount>,
+    
+    #[account(address = vesting_vault.mint @ BodyDfiError::InvalidMint)]
+    pub mint: Account<'info, Mint>,
+    
+    /// Beneficiary's associated token account, created if missing
+    #[account(
+        init_if_needed,
+        payer = beneficiary,
+        associated_token::mint = mint,
+        associated_token::authority = beneficiary
+    )]
+    pub beneficiary_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Revoke vesting account context
+#[derive(Accounts)]
+pub struct RevokeVesting<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_REWARDS) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(
+        constraint = token_mint.authority == authority.key() @ BodyDfiError::InvalidAuthority
+    )]
+    pub token_mint: Account<'info, TokenMint>,
+    
+    #[account(
+        mut,
+        seeds = [b"vesting", token_mint.key().as_ref(), vesting.beneficiary.as_ref(), &vesting.vesting_id.to_le_bytes()],
+        bump = vesting.bump
+    )]
+    pub vesting: Account<'info, VestingSchedule>,
+    
+    #[account(
+        mut,
+        address = vesting.vault @ BodyDfiError::InvalidTokenAccount
+    )]
+    pub vesting_vault: Account<'info, TokenAccount>,
+    
+    /// Unvested tokens are returned to the allocation vault
+    #[account(
+        mut,
+        seeds = [b"allocation-vault", token_mint.mint.as_ref()],
+        bump
+    )]
+    pub allocation_vault: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Vesting categories
+pub const VESTING_CATEGORY_TEAM: u8 = 0;
+pub const VESTING_CATEGORY_INVESTOR: u8 = 1;
+pub const VESTING_CATEGORY_ECOSYSTEM: u8 = 2;
+
+/// Vesting escrow for part of the BodyDFi token allocation
+#[account]
+pub struct VestingSchedule {
+    /// Token mint account of the vested token
+    pub token_mint: Pubkey,
+    
+    /// Beneficiary entitled to the vested tokens
+    pub beneficiary: Pubkey,
+    
+    /// Escrow token account holding the tokens
+    pub vault: Pubkey,
+    
+    /// Identifier distinguishing schedules of the same beneficiary
+    pub vesting_id: u64,
+    
+    /// Allocation category (0: Team, 1: Investor, 2: Ecosystem)
+    pub category: u8,
+    
+    /// Total amount vesting under this schedule
+    pub total_amount: u64,
+    
+    /// Amount already withdrawn by the beneficiary
+    pub withdrawn_amount: u64,
+    
+    /// Timestamp vesting starts accruing from
+    pub start_timestamp: i64,
+    
+    /// Timestamp before which nothing can be withdrawn
+    pub cliff_timestamp: i64,
+    
+    /// Timestamp at which everything is vested
+    pub end_timestamp: i64,
+    
+    /// Whether the authority may revoke the unvested part
+    pub revocable: bool,
+    
+    /// Whether the schedule has been revoked
+    pub is_revoked: bool,
+    
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl VestingSchedule {
+    pub const LEN: usize = 32 + 32 + 32 + 8 + 1 + 8 + 8 + 8 + 8 + 8 + 1 + 1 + 1;
+    
+    /// Amount vested at a given timestamp
+    pub fn vested_amount(&self, timestamp: i64) -> u64 {
+        if timestamp < self.cliff_timestamp {
+            return 0;
+        }
+        if timestamp >= self.end_timestamp || self.is_revoked {
+            return self.total_amount;
+        }
+        
+        let elapsed = (timestamp - self.start_timestamp) as u128;
+        let duration = (self.end_timestamp - self.start_timestamp) as u128;
+        ((self.total_amount as u128) * elapsed / duration) as u64
+    }
+}
+
+/// Propose token authority transfer account context
+#[derive(Accounts)]
+pub struct ProposeAuthorityTransfer<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"token-mint", token_mint.mint.as_ref()],
+        bump,
+        constraint = token_mint.authority == authority.key() @ BodyDfiError::InvalidAuthority
+    )]
+    pub token_mint: Account<'info, TokenMint>,
+}
+
+/// Accept token authority transfer account context
+#[derive(Accounts)]
+pub struct AcceptAuthorityTransfer<'info> {
+    pub pending_authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"token-mint", token_mint.mint.as_ref()],
+        bump,
+        constraint = token_mint.pending_authority == pending_authority.key() @ BodyDfiError::InvalidAuthority
+    )]
+    pub token_mint: Account<'info, TokenMint>,
+}
+
+/// Burn tokens account context
+#[derive(Accounts)]
+pub struct BurnTokens<'info> {
+    pub owner: Signer<'info>,
+    
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_REWARDS) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"token-mint", mint.key().as_ref()],
+        bump
+    )]
+    pub token_mint: Account<'info, TokenMint>,
+    
+    #[account(
+        mut,
+        address = token_mint.mint @ BodyDfiError::InvalidMint
+    )]
+    pub mint: Account<'info, Mint>,
+    
+    #[account(
+        mut,
+        constraint = owner_token_account.owner == owner.key() @ BodyDfiError::InvalidTokenAccount,
+        constraint = owner_token_account.mint == mint.key() @ BodyDfiError::InvalidMint
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+/// Reconcile supply account context
+#[derive(Accounts)]
+pub struct ReconcileSupply<'info> {
+    #[account(
+        seeds = [b"program-config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PAUSE_REWARDS) @ BodyDfiError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(
+        seeds = [b"token-mint", mint.key().as_ref()],
+        bump
+    )]
+    pub token_mint: Account<'info, TokenMint>,
+    
+    #[account(address = token_mint.mint @ BodyDfiError::InvalidMint)]
+    pub mint: Account<'info, Mint>,
+}
+
 // Import error code
-use crate::errors::BodyDfiError; 
\ No newline at end of file
+use crate::errors::BodyDfiError;
+use crate::config::config_state::{ProgramConfig, PAUSE_REWARDS};
+use crate::data_marketplace::data_state::{DataProvider, DataSubmission};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn schedule(initial_epoch_budget: u64, decay_bps: u16, decay_interval: u64) -> EmissionSchedule {
+        EmissionSchedule {
+            token_mint: Pubkey::default(),
+            start_timestamp: 0,
+            epoch_length: 86_400,
+            initial_epoch_budget,
+            decay_bps,
+            decay_interval,
+            current_epoch: 0,
+            current_epoch_budget: initial_epoch_budget,
+            current_epoch_emitted: 0,
+            total_emitted: 0,
+        }
+    }
+    
+    #[test]
+    fn budget_without_decay_is_constant() {
+        let schedule = schedule(1_000, 0, 1);
+        assert_eq!(schedule.budget_for_epoch(0), 1_000);
+        assert_eq!(schedule.budget_for_epoch(u64::MAX), 1_000);
+    }
+    
+    #[test]
+    fn budget_decays_at_interval_boundaries() {
+        let schedule = schedule(1_000_000, 5_000, 10);
+        assert_eq!(schedule.budget_for_epoch(0), 1_000_000);
+        assert_eq!(schedule.budget_for_epoch(9), 1_000_000);
+        assert_eq!(schedule.budget_for_epoch(10), 500_000);
+        assert_eq!(schedule.budget_for_epoch(19), 500_000);
+        assert_eq!(schedule.budget_for_epoch(30), 125_000);
+    }
+    
+    #[test]
+    fn budget_matches_repeated_decay() {
+        let schedule = schedule(1_000_000_000_000, 137, 1);
+        for epoch in 0..200u64 {
+            let expected = 1_000_000_000_000f64 * 0.9863f64.powi(epoch as i32);
+            let budget = schedule.budget_for_epoch(epoch);
+            assert!((budget as f64 - expected).abs() <= 1.0, "epoch {}: {} != {}", epoch, budget, expected);
+        }
+    }
+    
+    #[test]
+    fn budget_reaches_zero_with_full_decay() {
+        let schedule = schedule(1_000, 10_000, 1);
+        assert_eq!(schedule.budget_for_epoch(0), 1_000);
+        assert_eq!(schedule.budget_for_epoch(1), 0);
+    }
+    
+    #[test]
+    fn budget_for_distant_epoch_is_bounded() {
+        let schedule = schedule(u64::MAX, 1, 1);
+        assert_eq!(schedule.budget_for_epoch(u64::MAX), 0);
+        assert!(schedule.budget_for_epoch(1_000) < u64::MAX);
+    }
+    
+    fn vesting(total_amount: u64, is_revoked: bool) -> VestingSchedule {
+        VestingSchedule {
+            token_mint: Pubkey::default(),
+            beneficiary: Pubkey::default(),
+            vault: Pubkey::default(),
+            vesting_id: 0,
+            category: 0,
+            total_amount,
+            withdrawn_amount: 0,
+            start_timestamp: 1_000,
+            cliff_timestamp: 1_250,
+            end_timestamp: 2_000,
+            revocable: true,
+            is_revoked,
+            bump: 0,
+        }
+    }
+    
+    #[test]
+    fn nothing_vests_before_cliff() {
+        let schedule = vesting(1_000, false);
+        assert_eq!(schedule.vested_amount(0), 0);
+        assert_eq!(schedule.vested_amount(1_000), 0);
+        assert_eq!(schedule.vested_amount(1_249), 0);
+    }
+    
+    #[test]
+    fn vesting_accrues_linearly_from_start_at_cliff() {
+        let schedule = vesting(1_000, false);
+        assert_eq!(schedule.vested_amount(1_250), 250);
+        assert_eq!(schedule.vested_amount(1_500), 500);
+        assert_eq!(schedule.vested_amount(1_999), 999);
+    }
+    
+    #[test]
+    fn everything_vests_at_end() {
+        let schedule = vesting(1_000, false);
+        assert_eq!(schedule.vested_amount(2_000), 1_000);
+        assert_eq!(schedule.vested_amount(i64::MAX), 1_000);
+    }
+    
+    #[test]
+    fn revoked_schedule_keeps_remaining_amount_vested_after_cliff() {
+        let schedule = vesting(400, true);
+        assert_eq!(schedule.vested_amount(1_249), 0);
+        assert_eq!(schedule.vested_amount(1_250), 400);
+    }
+    
+    #[test]
+    fn large_amounts_do_not_overflow() {
+        let schedule = vesting(u64::MAX, false);
+        assert_eq!(schedule.vested_amount(1_500), u64::MAX / 2);
+    }
+    
+    #[test]
+    fn epoch_at_counts_whole_epochs() {
+        let schedule = schedule(1_000, 0, 1);
+        assert_eq!(schedule.epoch_at(-5), 0);
+        assert_eq!(schedule.epoch_at(86_399), 0);
+        assert_eq!(schedule.epoch_at(86_400), 1);
+    }
+}
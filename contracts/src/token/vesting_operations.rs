@@ -0,0 +1,207 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer};
+use crate::errors::BodyDfiError;
+use crate::token::token_state::*;
+
+/// Create a vesting schedule funded from the BodyDFi allocation vault
+#[allow(clippy::too_many_arguments)]
+pub fn create_vesting(
+    ctx: Context<CreateVesting>,
+    vesting_id: u64,
+    beneficiary: Pubkey,
+    category: u8,
+    amount: u64,
+    start_timestamp: i64,
+    cliff_duration: i64,
+    vesting_duration: i64,
+    revocable: bool,
+) -> Result<()> {
+    // Validate inputs
+    require!(
+        category <= VESTING_CATEGORY_ECOSYSTEM,
+        BodyDfiError::InvalidVestingSchedule
+    );
+    require!(amount > 0, BodyDfiError::InvalidVestingSchedule);
+    require!(cliff_duration >= 0, BodyDfiError::InvalidVestingSchedule);
+    require!(
+        vesting_duration >= cliff_duration,
+        BodyDfiError::InvalidVestingSchedule
+    );
+    
+    let vesting = &mut ctx.accounts.vesting;
+    
+    // Initialize vesting schedule
+    vesting.token_mint = ctx.accounts.token_mint.key();
+    vesting.beneficiary = beneficiary;
+    vesting.vault = ctx.accounts.vesting_vault.key();
+    vesting.vesting_id = vesting_id;
+    vesting.category = category;
+    vesting.total_amount = amount;
+    vesting.withdrawn_amount = 0;
+    vesting.start_timestamp = start_timestamp;
+    vesting.cliff_timestamp = start_timestamp.checked_add(cliff_duration)
+        .ok_or(BodyDfiError::InvalidVestingSchedule)?;
+    vesting.end_timestamp = start_timestamp.checked_add(vesting_duration)
+        .ok_or(BodyDfiError::InvalidVestingSchedule)?;
+    vesting.revocable = revocable;
+    vesting.is_revoked = false;
+    vesting.bump = ctx.bumps.vesting;
+    
+    // Move the tokens from the allocation vault into escrow
+    let mint_key = ctx.accounts.mint.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"token-mint",
+        mint_key.as_ref(),
+        &[ctx.bumps.token_mint],
+    ]];
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.allocation_vault.to_account_info(),
+        to: ctx.accounts.vesting_vault.to_account_info(),
+        authority: ctx.accounts.token_mint.to_account_info(),
+    };
+    
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+    
+    token::transfer(cpi_ctx, amount)?;
+    
+    // Emit event
+    emit!(VestingCreatedEvent {
+        vesting: ctx.accounts.vesting.key(),
+        beneficiary,
+        category,
+        amount,
+        cliff_timestamp: ctx.accounts.vesting.cliff_timestamp,
+        end_timestamp: ctx.accounts.vesting.end_timestamp,
+        revocable,
+    });
+    
+    Ok(())
+}
+
+/// Withdraw all currently vested tokens to the beneficiary
+pub fn withdraw_vested(ctx: Context<WithdrawVested>) -> Result<()> {
+    let vesting = &mut ctx.accounts.vesting;
+    let current_time = ctx.accounts.clock.unix_timestamp;
+    
+    let amount = vesting.vested_amount(current_time)
+        .checked_sub(vesting.withdrawn_amount)
+        .unwrap();
+    require!(amount > 0, BodyDfiError::NothingVested);
+    
+    vesting.withdrawn_amount = vesting.withdrawn_amount.checked_add(amount).unwrap();
+    
+    // Transfer from escrow, signed by the vesting PDA
+    let token_mint_key = vesting.token_mint;
+    let beneficiary_key = vesting.beneficiary;
+    let vesting_id_bytes = vesting.vesting_id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"vesting",
+        token_mint_key.as_ref(),
+        beneficiary_key.as_ref(),
+        &vesting_id_bytes,
+        &[vesting.bump],
+    ]];
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.vesting_vault.to_account_info(),
+        to: ctx.accounts.beneficiary_token_account.to_account_info(),
+        authority: vesting.to_account_info(),
+    };
+    
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+    
+    token::transfer(cpi_ctx, amount)?;
+    
+    // Emit event
+    emit!(VestedWithdrawnEvent {
+        vesting: vesting.key(),
+        beneficiary: beneficiary_key,
+        amount,
+        withdrawn_amount: vesting.withdrawn_amount,
+    });
+    
+    Ok(())
+}
+
+/// Revoke a vesting schedule, returning the unvested tokens to the
+/// allocation vault while leaving the vested part to the beneficiary
+pub fn revoke_vesting(ctx: Context<RevokeVesting>) -> Result<()> {
+    let vesting = &mut ctx.accounts.vesting;
+    let current_time = ctx.accounts.clock.unix_timestamp;
+    
+    require!(vesting.revocable, BodyDfiError::VestingNotRevocable);
+    require!(!vesting.is_revoked, BodyDfiError::VestingNotRevocable);
+    
+    // Freeze the schedule at the amount vested so far
+    let vested_amount = vesting.vested_amount(current_time);
+    let unvested_amount = vesting.total_amount.checked_sub(vested_amount).unwrap();
+    vesting.total_amount = vested_amount;
+    vesting.is_revoked = true;
+    
+    if unvested_amount > 0 {
+        // Return the unvested tokens, signed by the vesting PDA
+        let token_mint_key = vesting.token_mint;
+        let beneficiary_key = vesting.beneficiary;
+        let vesting_id_bytes = vesting.vesting_id.to_le_bytes();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            b"vesting",
+            token_mint_key.as_ref(),
+            beneficiary_key.as_ref(),
+            &vesting_id_bytes,
+            &[vesting.bump],
+        ]];
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.vesting_vault.to_account_info(),
+            to: ctx.accounts.allocation_vault.to_account_info(),
+            authority: vesting.to_account_info(),
+        };
+        
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+        
+        token::transfer(cpi_ctx, unvested_amount)?;
+    }
+    
+    // Emit event
+    emit!(VestingRevokedEvent {
+        vesting: vesting.key(),
+        beneficiary: vesting.beneficiary,
+        vested_amount,
+        returned_amount: unvested_amount,
+        revoked_at: current_time,
+    });
+    
+    Ok(())
+}
+
+/// Event emitted when a vesting schedule is created
+#[event]
+pub struct VestingCreatedEvent {
+    pub vesting: Pubkey,
+    pub beneficiary: Pubkey,
+    pub category: u8,
+    pub amount: u64,
+    pub cliff_timestamp: i64,
+    pub end_timestamp: i64,
+    pub revocable: bool,
+}
+
+/// Event emitted when vested tokens are withdrawn
+#[event]
+pub struct VestedWithdrawnEvent {
+    pub vesting: Pubkey,
+    pub beneficiary: Pubkey,
+    pub amount: u64,
+    pub withdrawn_amount: u64,
+}
+
+/// Event emitted when a vesting schedule is revoked
+#[event]
+pub struct VestingRevokedEvent {
+    pub vesting: Pubkey,
+    pub beneficiary: Pubkey,
+    pub vested_amount: u64,
+    pub returned_amount: u64,
+    pub revoked_at: i64,
+}
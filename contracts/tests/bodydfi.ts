@@ -17,11 +17,27 @@ describe('BodyDFi', () => {
   const user = wallet.payer;
   const testUser = Keypair.generate();
 
+  // Program config
+  const [programConfig] = PublicKey.findProgramAddressSync(
+    [Buffer.from('program-config')],
+    program.programId
+  );
+  const [programData] = PublicKey.findProgramAddressSync(
+    [program.programId.toBuffer()],
+    new PublicKey('BPFLoaderUpgradeab1e11111111111111111111111')
+  );
+
   // Token accounts
+  const moveMint = Keypair.generate();
+  const bodyDfiMint = Keypair.generate();
   let moveTokenMint: PublicKey;
   let bodyDfiTokenMint: PublicKey;
   let moveTokenMintBump: number;
   let bodyDfiTokenMintBump: number;
+  const [mintAuthority] = PublicKey.findProgramAddressSync(
+    [Buffer.from('mint-authority')],
+    program.programId
+  );
 
   // Data provider accounts
   const userId = `user_${Date.now()}`;
@@ -45,11 +61,68 @@ describe('BodyDFi', () => {
     await provider.connection.confirmTransaction(signature);
   });
 
+  describe('Program Config', () => {
+    it('Should initialize the program config', async () => {
+      await program.methods
+        .initializeProgramConfig(
+          user.publicKey, // treasury
+          PublicKey.default, // holder rewards vault, set once created
+          new anchor.BN(0) // feature flags
+        )
+        .accounts({
+          admin: user.publicKey,
+          programConfig,
+          program: program.programId,
+          programData,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const config = await program.account.programConfig.fetch(programConfig);
+      expect(config.admin.toString()).to.equal(user.publicKey.toString());
+      expect(config.guardian.toString()).to.equal(user.publicKey.toString());
+      expect(config.pauseFlags).to.equal(0);
+    });
+
+    it('Should block data submissions while paused', async () => {
+      const pausedUserId = `paused_${Date.now()}`;
+      const [pausedProviderPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('data-provider'), Buffer.from(pausedUserId)],
+        program.programId
+      );
+
+      await program.methods
+        .pause(1) // Data subsystem
+        .accounts({ signer: user.publicKey, programConfig })
+        .rpc();
+
+      try {
+        await program.methods
+          .registerDataProvider(pausedUserId, 0)
+          .accounts({
+            authority: user.publicKey,
+            programConfig,
+            dataProvider: pausedProviderPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+        expect.fail('Registration should fail while paused');
+      } catch (err) {
+        expect(err.toString()).to.include('ProgramPaused');
+      }
+
+      await program.methods
+        .unpause(1)
+        .accounts({ admin: user.publicKey, programConfig })
+        .rpc();
+    });
+  });
+
   describe('Token Operations', () => {
     it('Should initialize MOVE token', async () => {
       // Find PDA for token mint
       [moveTokenMint, moveTokenMintBump] = await PublicKey.findProgramAddressSync(
-        [Buffer.from('token-mint'), user.publicKey.toBuffer()],
+        [Buffer.from('token-mint'), moveMint.publicKey.toBuffer()],
         program.programId
       );
 
@@ -63,11 +136,14 @@ describe('BodyDFi', () => {
         )
         .accounts({
           authority: user.publicKey,
+          mintAuthority,
+          mint: moveMint.publicKey,
           tokenMint: moveTokenMint,
           systemProgram: SystemProgram.programId,
           tokenProgram: TOKEN_PROGRAM_ID,
           rent: anchor.web3.SYSVAR_RENT_PUBKEY,
         })
+        .signers([moveMint])
         .rpc();
 
       // Verify token was created
@@ -75,17 +151,28 @@ describe('BodyDFi', () => {
       expect(tokenMintAccount.name).to.equal('MOVE Token');
       expect(tokenMintAccount.symbol).to.equal('MOVE');
       expect(tokenMintAccount.isMoveToken).to.be.true;
+      expect(tokenMintAccount.mint.toString()).to.equal(moveMint.publicKey.toString());
+
+      // Verify the SPL mint is controlled by the program PDA
+      const mintInfo = await provider.connection.getParsedAccountInfo(moveMint.publicKey);
+      const parsedMint = (mintInfo.value.data as anchor.web3.ParsedAccountData).parsed.info;
+      expect(parsedMint.mintAuthority).to.equal(mintAuthority.toString());
+      expect(parsedMint.decimals).to.equal(9);
     });
 
     it('Should initialize BodyDFi token', async () => {
       // Find PDA for token mint
       [bodyDfiTokenMint, bodyDfiTokenMintBump] = await PublicKey.findProgramAddressSync(
-        [Buffer.from('token-mint'), user.publicKey.toBuffer()],
+        [Buffer.from('token-mint'), bodyDfiMint.publicKey.toBuffer()],
         program.programId
       );
 
       // Total supply: 1 billion with 9 decimals
-      const totalSupply = new anchor.BN(1_000_000_000_000_000_000);
+      const totalSupply = new anchor.BN('1000000000000000000');
+      const [allocationVault] = PublicKey.findProgramAddressSync(
+        [Buffer.from('allocation-vault'), bodyDfiMint.publicKey.toBuffer()],
+        program.programId
+      );
 
       // Initialize token
       await program.methods
@@ -98,11 +185,15 @@ describe('BodyDFi', () => {
         )
         .accounts({
           authority: user.publicKey,
+          mintAuthority,
+          mint: bodyDfiMint.publicKey,
           tokenMint: bodyDfiTokenMint,
+          allocationVault,
           systemProgram: SystemProgram.programId,
           tokenProgram: TOKEN_PROGRAM_ID,
           rent: anchor.web3.SYSVAR_RENT_PUBKEY,
         })
+        .signers([bodyDfiMint])
         .rpc();
 
       // Verify token was created
@@ -111,6 +202,10 @@ describe('BodyDFi', () => {
       expect(tokenMintAccount.symbol).to.equal('BDFI');
       expect(tokenMintAccount.isMoveToken).to.be.false;
       expect(tokenMintAccount.totalSupply.toString()).to.equal(totalSupply.toString());
+
+      // Verify the full supply landed in the program-owned allocation vault
+      const balance = await provider.connection.getTokenAccountBalance(allocationVault);
+      expect(balance.value.amount).to.equal(totalSupply.toString());
     });
   });
 
@@ -130,6 +225,7 @@ describe('BodyDFi', () => {
         )
         .accounts({
           authority: user.publicKey,
+          programConfig,
           dataProvider: dataProviderPda,
           systemProgram: SystemProgram.programId,
         })
@@ -169,6 +265,7 @@ describe('BodyDFi', () => {
         )
         .accounts({
           user: user.publicKey,
+          programConfig,
           dataProvider: dataProviderPda,
           dataSubmission: dataSubmissionPda,
           systemProgram: SystemProgram.programId,
@@ -209,8 +306,10 @@ describe('BodyDFi', () => {
         )
         .accounts({
           user: user.publicKey,
+          programConfig,
           dataProvider: dataProviderPda,
           dataListing: dataListingPda,
+          allowedPaymentMint: null, // Priced in SOL
           systemProgram: SystemProgram.programId,
           clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
         })